@@ -64,6 +64,7 @@ extern crate regex;
 
 use self::regex::Regex;
 use std::cmp::min;
+use std::collections::HashMap;
 use std::error::Error;
 use std::f64::consts::PI;
 use std::fmt;
@@ -74,13 +75,23 @@ fn round_with_precision(number: f64, precision: u8) -> f64 {
     (number * multiplier).round() / multiplier
 }
 
+/// The reasons, why a [ParseError](struct.ParseError.html) is returned.
+///
+/// Besides the errors of parsing strings (e.g. with [Color::new_string](struct.Color.html#method.new_string)),
+/// the crate reuses this error type for invalid numeric arguments of checked methods, instead of introducing another error type.
+/// More reasons may be added in future versions, so a match on this enum needs a wildcard arm.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParseErrorEnum {
     EmptyString,
     InvalidColorName,
     InvalidCssFunction,
     InvalidHexValue,
     InvalidAbbreviation,
+    UnexpectedAngleUnit,
+    MixedUnits,
+    /// Not a parse error: returned by [Color::try_mix](struct.Color.html#method.try_mix) for a weight outside of 0.0..=1.0 or NaN.
+    InvalidWeight,
     Unknown,
 }
 
@@ -91,12 +102,65 @@ pub struct ParseError {
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.reason)
+        match self.reason {
+            ParseErrorEnum::UnexpectedAngleUnit => write!(
+                f,
+                "UnexpectedAngleUnit: angle units (°, deg, grad, rad or turn) are only allowed for hue values"
+            ),
+            _ => write!(f, "{:?}", self.reason),
+        }
     }
 }
 
 impl Error for ParseError {}
 
+/// The options to control how forgiving [Color::new_string_with_options](struct.Color.html#method.new_string_with_options) parses strings.
+///
+/// The default options are used by [Color::new_string](struct.Color.html#method.new_string),
+/// which only accepts a pair of surrounding quotes.
+/// More options may be added in future versions, so start from the default options and enable the ones you need.
+///
+/// # Example
+/// ```
+/// use color_processing::ParseOptions;
+///
+/// let mut options = ParseOptions::default();
+/// options.allow_0x = true;
+/// assert!(options.allow_quotes);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Accepts css declarations and palette entries, e.g. `color: red;` or `red,`.
+    pub allow_css_prop: bool,
+    /// Accepts a single pair of surrounding single or double quotes, e.g. `"red"`.
+    pub allow_quotes: bool,
+    /// Accepts hex values with a `0x` prefix, e.g. `0xff0000`.
+    ///
+    /// Values with an alpha channel are read as `0xAARRGGBB` (or `0xARGB`), like the output of
+    /// [Color::to_argb_u32](struct.Color.html#method.to_argb_u32), e.g. `0x80ff0000` is a half transparent red.
+    pub allow_0x: bool,
+    /// Accepts hex values with 1 or 2 digits as grays, e.g. `#8` for `#888888` or `#80` for `#808080`.
+    pub allow_hex_gray: bool,
+    /// Accepts rgb values mixing numbers and percentages, e.g. `rgb(100%, 0, 0)`.
+    pub allow_mixed_units: bool,
+    /// Accepts css functions without parentheses and with space separated values, e.g. `rgb 255 0 0`.
+    pub lenient_whitespace: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            allow_css_prop: false,
+            allow_quotes: true,
+            allow_0x: false,
+            allow_hex_gray: false,
+            allow_mixed_units: false,
+            lenient_whitespace: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Color {
     pub red: u8,
@@ -121,6 +185,158 @@ impl Color {
     const RAD2DEG: f64 = 180.0 / PI;
     const DEG2RAD: f64 = PI / 180.0;
 
+    // the css color names, as parsed by new_string and preferred by to_css_string.
+    const KNOWN_COLORS: [(&str, KnownColors); 148] = [
+        ("aliceblue", KnownColors::AliceBlue),
+        ("antiquewhite", KnownColors::AntiqueWhite),
+        ("aqua", KnownColors::Aqua),
+        ("aquamarine", KnownColors::AquaMarine),
+        ("azure", KnownColors::Azure),
+        ("beige", KnownColors::Beige),
+        ("bisque", KnownColors::Bisque),
+        ("black", KnownColors::Black),
+        ("blanchedalmond", KnownColors::BlanchedAlmond),
+        ("blue", KnownColors::Blue),
+        ("blueviolet", KnownColors::BlueViolet),
+        ("brown", KnownColors::Brown),
+        ("burlywood", KnownColors::BurlyWood),
+        ("cadetblue", KnownColors::CadetBlue),
+        ("chartreuse", KnownColors::Chartreuse),
+        ("chocolate", KnownColors::Chocolate),
+        ("coral", KnownColors::Coral),
+        ("cornflowerblue", KnownColors::CornflowerBlue),
+        ("cornsilk", KnownColors::Cornsilk),
+        ("crimson", KnownColors::Crimson),
+        ("cyan", KnownColors::Cyan),
+        ("darkblue", KnownColors::DarkBlue),
+        ("darkcyan", KnownColors::DarkCyan),
+        ("darkgoldenrod", KnownColors::DarkGoldenrod),
+        ("darkgray", KnownColors::DarkGray),
+        ("darkgrey", KnownColors::DarkGray),
+        ("darkgreen", KnownColors::DarkGreen),
+        ("darkkhaki", KnownColors::DarkKhaki),
+        ("darkmagenta", KnownColors::DarkMagenta),
+        ("darkolivegreen", KnownColors::DarkOliveGreen),
+        ("darkorange", KnownColors::DarkOrange),
+        ("darkorchid", KnownColors::DarkOrchid),
+        ("darkred", KnownColors::DarkRed),
+        ("darksalmon", KnownColors::DarkSalmon),
+        ("darkseagreen", KnownColors::DarkSeaGreen),
+        ("darkslateblue", KnownColors::DarkSlateBlue),
+        ("darkslategray", KnownColors::DarkSlateGray),
+        ("darkslategrey", KnownColors::DarkSlateGray),
+        ("darkturquoise", KnownColors::DarkTurquoise),
+        ("darkviolet", KnownColors::DarkViolet),
+        ("deeppink", KnownColors::DeepPink),
+        ("deepskyblue", KnownColors::DeepSkyBlue),
+        ("dimgray", KnownColors::DimGray),
+        ("dimgrey", KnownColors::DimGray),
+        ("dodgerblue", KnownColors::DodgerBlue),
+        ("firebrick", KnownColors::Firebrick),
+        ("floralwhite", KnownColors::FloralWhite),
+        ("forestgreen", KnownColors::ForestGreen),
+        ("fuchsia", KnownColors::Fuchsia),
+        ("gainsboro", KnownColors::Gainsboro),
+        ("ghostwhite", KnownColors::GhostWhite),
+        ("gold", KnownColors::Gold),
+        ("goldenrod", KnownColors::Goldenrod),
+        ("gray", KnownColors::Gray),
+        ("grey", KnownColors::Gray),
+        ("green", KnownColors::Green),
+        ("greenyellow", KnownColors::GreenYellow),
+        ("honeydew", KnownColors::Honeydew),
+        ("hotpink", KnownColors::HotPink),
+        ("indianred", KnownColors::IndianRed),
+        ("indigo", KnownColors::Indigo),
+        ("ivory", KnownColors::Ivory),
+        ("khaki", KnownColors::Khaki),
+        ("lavender", KnownColors::Lavender),
+        ("lavenderblush", KnownColors::LavenderBlush),
+        ("lawngreen", KnownColors::LawnGreen),
+        ("lemonchiffon", KnownColors::LemonChiffon),
+        ("lightblue", KnownColors::LightBlue),
+        ("lightcoral", KnownColors::LightCoral),
+        ("lightcyan", KnownColors::LightCyan),
+        ("lightgoldenrodyellow", KnownColors::LightGoldenrodYellow),
+        ("lightgray", KnownColors::LightGray),
+        ("lightgrey", KnownColors::LightGray),
+        ("lightgreen", KnownColors::LightGreen),
+        ("lightpink", KnownColors::LightPink),
+        ("lightsalmon", KnownColors::LightSalmon),
+        ("lightseagreen", KnownColors::LightSeaGreen),
+        ("lightskyblue", KnownColors::LightSkyBlue),
+        ("lightslategray", KnownColors::LightSlateGray),
+        ("lightslategrey", KnownColors::LightSlateGray),
+        ("lightsteelblue", KnownColors::LightSteelBlue),
+        ("lightyellow", KnownColors::LightYellow),
+        ("lime", KnownColors::Lime),
+        ("limegreen", KnownColors::LimeGreen),
+        ("linen", KnownColors::Linen),
+        ("magenta", KnownColors::Magenta),
+        ("maroon", KnownColors::Maroon),
+        ("mediumaquamarine", KnownColors::MediumAquaMarine),
+        ("mediumblue", KnownColors::MediumBlue),
+        ("mediumorchid", KnownColors::MediumOrchid),
+        ("mediumpurple", KnownColors::MediumPurple),
+        ("mediumseagreen", KnownColors::MediumSeaGreen),
+        ("mediumslateblue", KnownColors::MediumSlateBlue),
+        ("mediumspringgreen", KnownColors::MediumSpringGreen),
+        ("mediumturquoise", KnownColors::MediumTurquoise),
+        ("mediumvioletred", KnownColors::MediumVioletRed),
+        ("midnightblue", KnownColors::MidnightBlue),
+        ("mintcream", KnownColors::MintCream),
+        ("mistyrose", KnownColors::MistyRose),
+        ("moccasin", KnownColors::Moccasin),
+        ("navajowhite", KnownColors::NavajoWhite),
+        ("navy", KnownColors::Navy),
+        ("oldlace", KnownColors::OldLace),
+        ("olive", KnownColors::Olive),
+        ("olivedrab", KnownColors::OliveDrab),
+        ("orange", KnownColors::Orange),
+        ("orangered", KnownColors::OrangeRed),
+        ("orchid", KnownColors::Orchid),
+        ("palegoldenrod", KnownColors::PaleGoldenrod),
+        ("palegreen", KnownColors::PaleGreen),
+        ("paleturquoise", KnownColors::PaleTurquoise),
+        ("palevioletred", KnownColors::PaleVioletRed),
+        ("papayawhip", KnownColors::PapayaWhip),
+        ("peachpuff", KnownColors::PeachPuff),
+        ("peru", KnownColors::Peru),
+        ("pink", KnownColors::Pink),
+        ("plum", KnownColors::Plum),
+        ("powderblue", KnownColors::PowderBlue),
+        ("purple", KnownColors::Purple),
+        ("red", KnownColors::Red),
+        ("rosybrown", KnownColors::RosyBrown),
+        ("royalblue", KnownColors::RoyalBlue),
+        ("saddlebrown", KnownColors::SaddleBrown),
+        ("salmon", KnownColors::Salmon),
+        ("sandybrown", KnownColors::SandyBrown),
+        ("seagreen", KnownColors::SeaGreen),
+        ("seashell", KnownColors::SeaShell),
+        ("sienna", KnownColors::Sienna),
+        ("silver", KnownColors::Silver),
+        ("skyblue", KnownColors::SkyBlue),
+        ("slateblue", KnownColors::SlateBlue),
+        ("slategray", KnownColors::SlateGray),
+        ("slategrey", KnownColors::SlateGray),
+        ("snow", KnownColors::Snow),
+        ("springgreen", KnownColors::SpringGreen),
+        ("steelblue", KnownColors::SteelBlue),
+        ("tan", KnownColors::Tan),
+        ("teal", KnownColors::Teal),
+        ("thistle", KnownColors::Thistle),
+        ("tomato", KnownColors::Tomato),
+        ("transparent", KnownColors::Transparent),
+        ("turquoise", KnownColors::Turquoise),
+        ("violet", KnownColors::Violet),
+        ("wheat", KnownColors::Wheat),
+        ("white", KnownColors::White),
+        ("whitesmoke", KnownColors::WhiteSmoke),
+        ("yellow", KnownColors::Yellow),
+        ("yellowgreen", KnownColors::YellowGreen),
+    ];
+
     /// Gets a new Color struct, that represents the "black"-color.
     ///
     /// # Example
@@ -1306,6 +1522,19 @@ impl Color {
         )
     }
 
+    /// Gets a new opaque Color struct from a Lab struct, like [new_lab](#method.new_lab).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, Lab};
+    ///
+    /// let red = Color::from_lab(Lab { l: 53.24, a: 80.09, b: 67.2 });
+    /// assert_eq!(red.to_hex_string(), "#FF0000");
+    /// ```
+    pub fn from_lab(lab: Lab) -> Color {
+        Color::new_lab(lab.l, lab.a, lab.b)
+    }
+
     /// Gets a new Color struct, that represents a color with the lightness, chroma and hue values.
     ///
     /// # Example
@@ -1412,6 +1641,40 @@ impl Color {
         Color::new_rgba(r, g, b, a)
     }
 
+    /// Gets a new opaque Color struct from a Lch struct, like [new_lch](#method.new_lch).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, Lch};
+    ///
+    /// let red = Color::from_lch(Lch { l: 53.24, c: 104.55, h: 40.0 });
+    /// assert_eq!(red.to_hex_string(), "#FF0000");
+    /// ```
+    pub fn from_lch(lch: Lch) -> Color {
+        Color::new_lch(lch.l, lch.c, lch.h)
+    }
+
+    /// Gets a new Color struct from an u32 number, whose bytes are in the given channel order (from the most to the least significant byte).
+    /// This is the inverse of [to_packed_u32](#method.to_packed_u32).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{ChannelOrder, Color};
+    ///
+    /// let color = Color::from_packed_u32(0x80563412, ChannelOrder::Abgr);
+    ///
+    /// assert_eq!([0x12, 0x34, 0x56, 0x80], color.channels());
+    /// ```
+    pub fn from_packed_u32(value: u32, order: ChannelOrder) -> Color {
+        let [b0, b1, b2, b3] = value.to_be_bytes();
+        match order {
+            ChannelOrder::Argb => Color::new_rgba(b1, b2, b3, b0),
+            ChannelOrder::Rgba => Color::new_rgba(b0, b1, b2, b3),
+            ChannelOrder::Abgr => Color::new_rgba(b3, b2, b1, b0),
+            ChannelOrder::Bgra => Color::new_rgba(b2, b1, b0, b3),
+        }
+    }
+
     /// Gets a new Color struct, that represents a color with the given red, green and blue values.
     ///
     /// * The value range of red, green and blue is from 0 to 255.
@@ -1465,6 +1728,7 @@ impl Color {
     ///
     /// * Examples
     ///   * [known color names](#known-color-names)
+    ///   * [system color names](#system-color-names)
     ///   * [abbreviated names](#abbreviated-names)
     ///   * [hex notation](#hex-notation)
     ///   * [rgb(a) notation](#rgb(a)-notation)
@@ -1473,6 +1737,8 @@ impl Color {
     ///   * [hsl(a) notation](#hsl(a)-notation)
     ///   * [hsv(a) notation](#hsv(a)-notation)
     ///   * [hwb(a) notation](#hwb(a)-notation)
+    ///   * [lab notation](#lab-notation)
+    ///   * [oklab and oklch notation](#oklab-and-oklch-notation)
     ///
     /// <a name="known-color-names"></a>
     /// # Example (known color names)
@@ -1485,6 +1751,49 @@ impl Color {
     /// assert_eq!(0, red.green);
     /// assert_eq!(0, red.blue);
     /// assert_eq!(255, red.alpha);
+    ///
+    /// // a single pair of surrounding single or double quotes is ignored.
+    /// let quoted_red = Color::new_string("'red'").unwrap();
+    /// assert_eq!(255, quoted_red.red);
+    /// ```
+    ///
+    /// <a name="system-color-names"></a>
+    /// # Example (system color names)
+    /// The [css system colors](https://www.w3.org/TR/css-color-4/#css-system-colors) are mapped to the defaults of a light theme.
+    /// The keywords are case-insensitive.
+    ///
+    /// | Keyword          | Default |
+    /// |------------------|---------|
+    /// | AccentColor      | #0078D7 |
+    /// | AccentColorText  | #FFFFFF |
+    /// | ActiveText       | #FF0000 |
+    /// | ButtonBorder     | #767676 |
+    /// | ButtonFace       | #EFEFEF |
+    /// | ButtonText       | #000000 |
+    /// | Canvas           | #FFFFFF |
+    /// | CanvasText       | #000000 |
+    /// | Field            | #FFFFFF |
+    /// | FieldText        | #000000 |
+    /// | GrayText         | #808080 |
+    /// | Highlight        | #B5D5FF |
+    /// | HighlightText    | #000000 |
+    /// | LinkText         | #0000EE |
+    /// | Mark             | #FFFF00 |
+    /// | MarkText         | #000000 |
+    /// | SelectedItem     | #0078D7 |
+    /// | SelectedItemText | #FFFFFF |
+    /// | VisitedText      | #551A8B |
+    ///
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let canvas = Color::new_string("Canvas").unwrap();
+    /// let canvas_text = Color::new_string("CanvasText").unwrap();
+    /// let link_text = Color::new_string("LinkText").unwrap();
+    ///
+    /// assert_eq!("#FFFFFF", canvas.to_hex_string());
+    /// assert_eq!("#000000", canvas_text.to_hex_string());
+    /// assert_eq!("#0000EE", link_text.to_hex_string());
     /// ```
     ///
     /// <a name="abbreviated-names"></a>
@@ -1537,7 +1846,7 @@ impl Color {
     /// <a name="rgb(a)-notation"></a>
     /// # Example (rgb(a) notation)
     /// ```
-    /// use color_processing::Color;
+    /// use color_processing::{Color, ParseErrorEnum};
     ///
     /// let red = Color::new_string("rgb(255, 0, 0)").unwrap();
     ///
@@ -1566,6 +1875,19 @@ impl Color {
     /// assert_eq!(255, yellow.green);
     /// assert_eq!(0, yellow.blue);
     /// assert_eq!(128, yellow.alpha);
+    ///
+    /// // fractional values are rounded to the nearest integer and clamped to the range from 0 to 255.
+    /// let dark_red = Color::new_string("rgb(127.5, 0, 0)").unwrap();
+    /// assert_eq!(128, dark_red.red);
+    ///
+    /// // the css4 syntax with space separated values and a slash before the alpha value is also supported,
+    /// // but commas and spaces must not be mixed within one function.
+    /// let transparent_blue = Color::new_string("rgb(0 0 255 / 50%)").unwrap();
+    /// assert_eq!(128, transparent_blue.alpha);
+    ///
+    /// // the red, green and blue values must either be all numbers or all percentages.
+    /// let error = Color::new_string("rgb(100%, 0, 0)").unwrap_err();
+    /// assert_eq!(ParseErrorEnum::MixedUnits, error.reason);
     /// ```
     ///
     /// <a name="gray-notation"></a>
@@ -1601,7 +1923,7 @@ impl Color {
     /// <a name="cmyk-notation"></a>
     /// # Example (cmyk notation)
     /// ```
-    /// use color_processing::Color;
+    /// use color_processing::{Color, ParseErrorEnum};
     ///
     /// let red = Color::new_string("cmyk(0%, 100%, 100%, 0%)").unwrap();
     ///
@@ -1609,6 +1931,10 @@ impl Color {
     /// assert_eq!(0, red.green);
     /// assert_eq!(0, red.blue);
     /// assert_eq!(255, red.alpha);
+    ///
+    /// // angle units are only allowed for hue values, so they are rejected in rgb(), cmyk() and gray().
+    /// let error = Color::new_string("rgb(120deg, 0, 0)").unwrap_err();
+    /// assert_eq!(ParseErrorEnum::UnexpectedAngleUnit, error.reason);
     /// ```
     ///
     /// <a name="hsl(a)-notation"></a>
@@ -1633,6 +1959,16 @@ impl Color {
     /// assert_eq!(transparent_green.green, 255);
     /// assert_eq!(transparent_green.blue, 0);
     /// assert_eq!(transparent_green.alpha, 128);
+    ///
+    /// // the alpha value is also accepted without the trailing "a" in the function name.
+    /// let transparent_green = Color::new_string("hsl(120°, 100%, 50%, 0.5)").unwrap();
+    /// assert_eq!(transparent_green.alpha, 128);
+    ///
+    /// // besides "°" and "deg", the hue also accepts the css angle units "grad", "rad" and "turn".
+    /// let cyan = Color::new_string("hsl(0.5turn, 100%, 50%)").unwrap();
+    /// assert_eq!(cyan.red, 0);
+    /// assert_eq!(cyan.green, 255);
+    /// assert_eq!(cyan.blue, 255);
     /// ```
     ///
     /// <a name="hsv(a)-notation"></a>
@@ -1682,109 +2018,222 @@ impl Color {
     /// assert_eq!(transparent_green.blue, 0);
     /// assert_eq!(transparent_green.alpha, 128);
     /// ```
+    ///
+    /// <a name="lab-notation"></a>
+    /// # Example (lab notation)
+    /// The lightness can be a number from 0.0 to 100.0 or a percentage, 100% of the a and b values are 125.0.
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("lab(53.24 80.09 67.2)").unwrap();
+    /// assert_eq!("#FF0000", red.to_hex_string());
+    ///
+    /// let transparent_red = Color::new_string("lab(53.24% 64.072% 53.76% / 0.5)").unwrap();
+    /// assert_eq!("#FF000080", transparent_red.to_hex_string());
+    /// ```
+    ///
+    /// <a name="oklab-and-oklch-notation"></a>
+    /// # Example (oklab and oklch notation)
+    /// The lightness can be a number from 0.0 to 1.0 or a percentage, 100% of the a, b and chroma values are 0.4.
+    /// The oklch hue accepts the same angle units as hsl and any value can be the keyword `none` (e.g. an achromatic hue), which counts as 0.
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("oklab(0.628 0.2249 0.1258)").unwrap();
+    /// assert_eq!("#FF0000", red.to_hex_string());
+    ///
+    /// let transparent_red = Color::new_string("oklch(62.8% 0.2577 29.23 / 0.5)").unwrap();
+    /// assert_eq!("#FF000080", transparent_red.to_hex_string());
+    ///
+    /// let gray = Color::new_string("oklch(0.5999 0 none)").unwrap();
+    /// assert_eq!("#808080", gray.to_hex_string());
+    /// ```
     pub fn new_string<S: Into<String>>(string: S) -> Result<Color, ParseError> {
-        let real_string: String = string.into();
-        let trimmed_str = real_string.trim();
-        let normalized_string = trimmed_str.to_lowercase();
-        let normalized_str = normalized_string.as_str();
+        Color::new_string_with_options(string, &ParseOptions::default())
+    }
 
-        if normalized_str.len() == 0 {
-            return Err(ParseError {
-                reason: ParseErrorEnum::EmptyString,
-            });
-        }
+    /// Gets a new Color struct, that represents a color parsed from the given string, like [new_string](#method.new_string),
+    /// but with a more lenient syntax.
+    ///
+    /// Additionally to all formats of [new_string](#method.new_string), css functions are accepted without parentheses
+    /// and with space separated values, as written by some minimal config formats (e.g. `rgb 255 0 0`).
+    /// This is a shortcut for [new_string_with_options](#method.new_string_with_options) with the `lenient_whitespace` option.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string_lenient("rgb 255 0 0").unwrap();
+    /// assert_eq!("#FF0000", red.to_hex_string());
+    ///
+    /// let transparent_green = Color::new_string_lenient("hsla 120 100% 50% 0.5").unwrap();
+    /// assert_eq!("rgba(0, 255, 0, 0.5)", transparent_green.to_rgb_string());
+    ///
+    /// // the strict parser requires parentheses.
+    /// assert!(Color::new_string("rgb 255 0 0").is_err());
+    /// ```
+    pub fn new_string_lenient<S: Into<String>>(string: S) -> Result<Color, ParseError> {
+        Color::new_string_with_options(
+            string,
+            &ParseOptions {
+                lenient_whitespace: true,
+                ..Default::default()
+            },
+        )
+    }
 
-        if normalized_str.len() == 2 {
-            match Color::try_parse_abbr_color(normalized_str) {
-                Some(color) => {
-                    return Ok(Color {
-                        red: color.red,
-                        green: color.green,
-                        blue: color.blue,
-                        alpha: color.alpha,
-                        original_string: real_string,
-                    });
-                }
-                None => {
-                    return Err(ParseError {
-                        reason: ParseErrorEnum::InvalidAbbreviation,
-                    });
-                }
-            }
+    /// Gets a new Color struct, that represents a color parsed from the given string with the given parse options.
+    ///
+    /// With the default options the string is parsed like with [new_string](#method.new_string),
+    /// each enabled option accepts an additional kind of malformed input (see [ParseOptions](struct.ParseOptions.html)).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, ParseOptions};
+    ///
+    /// let mut options = ParseOptions::default();
+    /// options.allow_css_prop = true;
+    /// options.allow_0x = true;
+    ///
+    /// let red = Color::new_string_with_options("color: #ff0000;", &options).unwrap();
+    /// assert_eq!("#FF0000", red.to_hex_string());
+    ///
+    /// let green = Color::new_string_with_options("0x00ff00", &options).unwrap();
+    /// assert_eq!("#00FF00", green.to_hex_string());
+    ///
+    /// // 0x values with an alpha channel are read as argb.
+    /// let transparent_blue = Color::new_string_with_options("0x800000ff", &options).unwrap();
+    /// assert_eq!([0, 0, 255, 128], transparent_blue.channels());
+    ///
+    /// // the 0x prefix is only accepted with the allow_0x option.
+    /// assert!(Color::new_string_with_options("0x00ff00", &ParseOptions::default()).is_err());
+    /// ```
+    pub fn new_string_with_options<S: Into<String>>(
+        string: S,
+        options: &ParseOptions,
+    ) -> Result<Color, ParseError> {
+        lazy_static! {
+            // cap[1] -> css property name (e.g. "color" in "color: red")
+            static ref RE_CSS_PROPERTY_PREFIX: Regex = Regex::new(r"^([a-zA-Z-]+)\s*:").unwrap();
         }
 
-        let first_char = normalized_str.chars().nth(0).unwrap();
-        let invalid_hex_char_position =
-            normalized_str.find(|c| c < '0' || c > '9' && c < 'a' || c > 'f');
-        if first_char == '#' || invalid_hex_char_position.is_none() {
-            match Color::try_parse_hex(normalized_str) {
-                Some(color) => {
-                    return Ok(Color {
-                        red: color.red,
-                        green: color.green,
-                        blue: color.blue,
-                        alpha: color.alpha,
-                        original_string: real_string,
-                    });
-                }
-                None => {
-                    return Err(ParseError {
-                        reason: ParseErrorEnum::InvalidHexValue,
-                    });
-                }
+        let real_string: String = string.into();
+        let mut trimmed_str = real_string.trim();
+        if options.allow_css_prop {
+            // declarations copied from stylesheets or palettes (e.g. "color: red;" or "red,").
+            trimmed_str = trimmed_str.trim_end_matches([';', ',']).trim_end();
+            if let Some(property) = RE_CSS_PROPERTY_PREFIX.find(trimmed_str) {
+                trimmed_str = trimmed_str[property.end()..].trim_start();
             }
         }
-
-        match normalized_str.find('(') {
-            Some(parentheses_position) => {
-                if parentheses_position > 0 {
-                    match Color::try_parse_css_function(normalized_str) {
-                        Some(color) => {
-                            return Ok(Color {
-                                red: color.red,
-                                green: color.green,
-                                blue: color.blue,
-                                alpha: color.alpha,
-                                original_string: real_string,
-                            });
-                        }
-                        None => {
-                            return Err(ParseError {
-                                reason: ParseErrorEnum::InvalidCssFunction,
-                            });
-                        }
+        if options.allow_quotes {
+            trimmed_str = Color::strip_quotes(trimmed_str);
+        }
+        let mut normalized_string = trimmed_str.to_lowercase();
+        if options.allow_0x {
+            if let Some(hex) = normalized_string.strip_prefix("0x") {
+                // the alpha channel leads in 0x values (argb), but trails in hex strings (rgba).
+                normalized_string = match hex.len() {
+                    4 | 8 if hex.is_char_boundary(hex.len() / 4) => {
+                        let (alpha, rgb) = hex.split_at(hex.len() / 4);
+                        format!("#{}{}", rgb, alpha)
                     }
-                } else {
-                    return Err(ParseError {
-                        reason: ParseErrorEnum::Unknown,
-                    });
+                    _ => format!("#{}", hex),
+                };
+            }
+        }
+        if options.allow_hex_gray {
+            if let Some(hex) = normalized_string.strip_prefix('#') {
+                if (hex.len() == 1 || hex.len() == 2) && hex.chars().all(|c| c.is_ascii_hexdigit())
+                {
+                    normalized_string = format!("#{}", hex.repeat(6 / hex.len()));
                 }
             }
-            None => {}
         }
 
-        if normalized_str.find(|c| c < 'a' || c > 'z').is_none() {
-            match Color::try_parse_known_color(normalized_str) {
-                Some(color) => {
-                    return Ok(Color {
-                        red: color.red,
-                        green: color.green,
-                        blue: color.blue,
-                        alpha: color.alpha,
-                        original_string: real_string,
-                    });
-                }
-                None => {
-                    return Err(ParseError {
-                        reason: ParseErrorEnum::InvalidColorName,
-                    });
-                }
+        let mut result =
+            Color::try_parse_normalized(normalized_string.as_str(), options.allow_mixed_units);
+        if options.lenient_whitespace && result.is_err() {
+            if let Some(lenient_result) = Color::try_parse_css_function_without_parentheses(
+                normalized_string.as_str(),
+                options.allow_mixed_units,
+            ) {
+                result = lenient_result;
             }
         }
 
-        return Err(ParseError {
-            reason: ParseErrorEnum::Unknown,
+        match result {
+            Ok(color) => Ok(Color {
+                red: color.red,
+                green: color.green,
+                blue: color.blue,
+                alpha: color.alpha,
+                original_string: real_string,
+            }),
+            Err(reason) => Err(ParseError { reason }),
+        }
+    }
+
+    /// Gets a new Color struct, that represents a color parsed from the given string, like [new_string](#method.new_string),
+    /// but the given map of custom color names (e.g. brand colors) is checked first.
+    ///
+    /// The custom color names are matched case-insensitive, after the string is trimmed and stripped of a single pair of surrounding quotes.
+    /// Lowercase keys are found with a single lookup, all other keys are compared one by one.
+    /// If several keys only differ in case, the lowercase key wins (without a lowercase key, it is unspecified which one matches).
+    /// If the string doesn't match a custom color name, it's parsed like with [new_string](#method.new_string).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut names = HashMap::new();
+    /// names.insert(String::from("brand-primary"), Color::new_rgb(0x12, 0x34, 0x56));
+    ///
+    /// let brand_primary = Color::new_string_with_names("Brand-Primary", &names).unwrap();
+    /// assert_eq!("#123456", brand_primary.to_hex_string());
+    ///
+    /// let red = Color::new_string_with_names("red", &names).unwrap();
+    /// assert_eq!("#FF0000", red.to_hex_string());
+    /// ```
+    pub fn new_string_with_names<S: Into<String>>(
+        string: S,
+        names: &HashMap<String, Color>,
+    ) -> Result<Color, ParseError> {
+        let real_string: String = string.into();
+        let normalized_string = Color::strip_quotes(real_string.trim()).to_lowercase();
+        // lowercase keys are found directly, other keys are compared case-insensitive.
+        let custom_color = names.get(&normalized_string).or_else(|| {
+            names
+                .iter()
+                .find(|(name, _)| name.to_lowercase() == normalized_string)
+                .map(|(_, color)| color)
         });
+
+        match custom_color {
+            Some(color) => Ok(Color {
+                red: color.red,
+                green: color.green,
+                blue: color.blue,
+                alpha: color.alpha,
+                original_string: real_string,
+            }),
+            None => Color::new_string(real_string),
+        }
+    }
+
+    fn strip_quotes(string: &str) -> &str {
+        // values from json/toml-like sources sometimes arrive with surrounding quotes.
+        for quote in ['"', '\''] {
+            if let Some(unquoted_str) = string
+                .strip_prefix(quote)
+                .and_then(|str| str.strip_suffix(quote))
+            {
+                return unquoted_str.trim();
+            }
+        }
+
+        string
     }
 
     /// Gets a new Color struct, that represents a color with the given temperature in kelvin.  
@@ -1805,12 +2254,26 @@ impl Color {
     /// assert_eq!(daylight.to_hex_string(), "#FFFAFE");
     /// ```
     pub fn new_temperature(kelvin: u16) -> Color {
-        let kelvin = if kelvin > 30_000 {
-            30_000.0f64
-        } else {
-            kelvin as f64
-        };
-        let temp = kelvin / 100.0;
+        Color::new_temperature_f64(kelvin.min(30_000) as f64)
+    }
+
+    /// Gets a new Color struct, that represents a color with the given fractional temperature in kelvin.
+    ///
+    /// This uses the same approximation as [new_temperature](#method.new_temperature), but the temperature isn't capped at 30000 Kelvin.
+    /// The useful range goes from about 1000 to 40000 Kelvin, negative temperatures are treated as 0 Kelvin.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let daylight = Color::new_temperature_f64(6_500.0);
+    /// let blue_sky = Color::new_temperature_f64(35_000.0);
+    ///
+    /// assert_eq!(daylight, Color::new_temperature(6_500));
+    /// assert_eq!(blue_sky.to_hex_string(), "#9DBDFF");
+    /// ```
+    pub fn new_temperature_f64(kelvin: f64) -> Color {
+        let temp = kelvin.max(0.0) / 100.0;
         let rgb = if temp < 66.0 {
             let mut g = temp - 2.0;
             g = -155.25485562709179 - 0.44596950469579133 * g + 104.49216199393888 * g.ln();
@@ -2146,6 +2609,66 @@ impl Color {
         )
     }
 
+    /// Gets the red, green, blue and alpha values of the color as an array.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!([0, 255, 0, 128], transparent_green.channels());
+    /// ```
+    pub fn channels(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
+    /// Gets the highest of the red, green and blue values of the color. The alpha value is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let color = Color::new_rgb(30, 200, 100);
+    ///
+    /// assert_eq!(200, color.max_channel());
+    /// ```
+    pub fn max_channel(&self) -> u8 {
+        self.red.max(self.green).max(self.blue)
+    }
+
+    /// Gets the lowest of the red, green and blue values of the color. The alpha value is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let color = Color::new_rgb(30, 200, 100);
+    ///
+    /// assert_eq!(30, color.min_channel());
+    /// ```
+    pub fn min_channel(&self) -> u8 {
+        self.red.min(self.green).min(self.blue)
+    }
+
+    /// Gets the rgb chroma of the color, which is the difference between the highest and the lowest channel.
+    ///
+    /// This is a cheap measure for the colorfulness without converting the color to hsl or lch.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_rgb(255, 0, 0);
+    /// let gray = Color::new_rgb(128, 128, 128);
+    ///
+    /// assert_eq!(255, red.chroma_rgb());
+    /// assert_eq!(0, gray.chroma_rgb());
+    /// ```
+    pub fn chroma_rgb(&self) -> u8 {
+        self.max_channel() - self.min_channel()
+    }
+
     fn get_xyz(&self) -> (f64, f64, f64) {
         let r = Color::rgb_xyz(self.red);
         let g = Color::rgb_xyz(self.green);
@@ -2224,6 +2747,46 @@ impl Color {
         (l, c, h, alpha)
     }
 
+    /// Gets the lightness, a and b values of the color as a Lab struct, like [get_laba](#method.get_laba) without the alpha value.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, Lab};
+    ///
+    /// let green = Color::new_string("rgb(0, 255, 0)").unwrap();
+    ///
+    /// assert_eq!(Lab { l: 87.73, a: -86.18, b: 83.18 }, green.get_lab());
+    /// ```
+    pub fn get_lab(&self) -> Lab {
+        let laba = self.get_laba();
+        Lab {
+            l: laba.0,
+            a: laba.1,
+            b: laba.2,
+        }
+    }
+
+    /// Gets the lightness, chroma and hue values of the color as a Lch struct, like [get_lcha](#method.get_lcha) without the alpha value.
+    ///
+    /// The hue is NaN for achromatic colors.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, Lch};
+    ///
+    /// let green = Color::new_string("rgb(0, 255, 0)").unwrap();
+    ///
+    /// assert_eq!(Lch { l: 87.73, c: 119.77, h: 136.01 }, green.get_lch());
+    /// ```
+    pub fn get_lch(&self) -> Lch {
+        let lcha = self.get_lcha();
+        Lch {
+            l: lcha.0,
+            c: lcha.1,
+            h: lcha.2,
+        }
+    }
+
     fn get_rgb_from_cmyk(mut c: f64, mut m: f64, mut y: f64, mut k: f64) -> (u8, u8, u8) {
         if c < 0.0 {
             c = 0.0;
@@ -2502,6 +3065,58 @@ impl Color {
         }
     }
 
+    /// Composites this color over a background color (alpha compositing with the "source over" operator).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    /// let white = Color::new_string("white").unwrap();
+    ///
+    /// assert_eq!("#FF7F7F", transparent_red.blend_over(&white).to_hex_string());
+    /// ```
+    pub fn blend_over(&self, background: &Color) -> Color {
+        let alpha = self.alpha as f64 / 255.0;
+        let background_alpha = background.alpha as f64 / 255.0 * (1.0 - alpha);
+        let final_alpha = alpha + background_alpha;
+        if final_alpha == 0.0 {
+            return Color::new_rgba(0, 0, 0, 0);
+        }
+
+        let blend = |value: u8, background_value: u8| -> u8 {
+            ((value as f64 * alpha + background_value as f64 * background_alpha) / final_alpha)
+                .round() as u8
+        };
+
+        Color::new_rgba(
+            blend(self.red, background.red),
+            blend(self.green, background.green),
+            blend(self.blue, background.blue),
+            (final_alpha * 255.0).round() as u8,
+        )
+    }
+
+    /// Composites this color over both tiles of a checkerboard, as color pickers show transparent colors.
+    ///
+    /// Returns the color over the light tile and the color over the dark tile, see [blend_over](#method.blend_over).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    /// let white = Color::new_string("white").unwrap();
+    /// let light_gray = Color::new_rgb(204, 204, 204);
+    /// let (over_white, over_light_gray) = transparent_red.over_checkerboard(&white, &light_gray);
+    ///
+    /// assert_eq!("#FF7F7F", over_white.to_hex_string());
+    /// assert_eq!("#E66666", over_light_gray.to_hex_string());
+    /// ```
+    pub fn over_checkerboard(&self, light: &Color, dark: &Color) -> (Color, Color) {
+        (self.blend_over(light), self.blend_over(dark))
+    }
+
     /// Gets a brightened color by a specified amount.
     ///
     /// # Example
@@ -2539,40 +3154,115 @@ impl Color {
         Color::new_lcha(new_l, laba.1, laba.2, laba.3)
     }
 
-    /// Gets a grayscaled color from the color.
+    /// Gets a darker color by mixing the current color with black in the sRGB color space.
     ///
-    /// This method uses the default formula used by PAL and NTSC systems.  
-    /// `Y = 0.299 * R + 0.587 * G + 0.114 * B`
+    /// The percentage goes from 0.0 (unchanged) to 1.0 (black), values out of this range are clamped.
+    /// This matches the css function `color-mix(in srgb, <color> (1 - pct), black)`, e.g. `darken_pct(0.2)` is the same as
+    /// `color-mix(in srgb, <color> 80%, black)`. The alpha value of the current color is kept.
+    /// Unlike [darken](#method.darken), which lowers the lightness in the LCH color space, the hue and the saturation stay the same.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
-    /// let red = Color::new_string("rgb(255, 0, 0)").unwrap();
-    /// let grayscaled_red = red.grayscale();
+    /// let red = Color::new_string("red").unwrap();
     ///
-    /// assert_eq!(76, grayscaled_red.red);
+    /// assert_eq!("#CC0000", red.darken_pct(0.2).to_hex_string());
+    /// assert_eq!("#000000", red.darken_pct(1.0).to_hex_string());
+    /// ```
+    pub fn darken_pct(&self, pct: f64) -> Color {
+        let black = Color::new_rgba(0, 0, 0, self.alpha);
+        self.mix(&black, pct, MixSpace::Rgb)
+    }
+
+    /// Gets a lighter color by mixing the current color with white in the sRGB color space.
+    ///
+    /// The percentage goes from 0.0 (unchanged) to 1.0 (white), values out of this range are clamped.
+    /// This matches the css function `color-mix(in srgb, <color> (1 - pct), white)`, e.g. `lighten_pct(0.2)` is the same as
+    /// `color-mix(in srgb, <color> 80%, white)`. The alpha value of the current color is kept.
+    /// Unlike [brighten](#method.brighten), which raises the lightness in the LCH color space, the result is a tint of the color.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    ///
+    /// assert_eq!("#FF3333", red.lighten_pct(0.2).to_hex_string());
+    /// assert_eq!("#FFFFFF", red.lighten_pct(1.0).to_hex_string());
+    /// ```
+    pub fn lighten_pct(&self, pct: f64) -> Color {
+        let white = Color::new_rgba(255, 255, 255, self.alpha);
+        self.mix(&white, pct, MixSpace::Rgb)
+    }
+
+    /// Gets a border color for the color as fill, that is slightly darker or lighter than the fill.
+    ///
+    /// Light colors (see [is_light](#method.is_light)) are darkened and dark colors are brightened by the amount,
+    /// as with [darken](#method.darken) and [brighten](#method.brighten).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let light_yellow = Color::new_string("lightyellow").unwrap();
+    /// let navy = Color::new_string("navy").unwrap();
+    ///
+    /// assert!(light_yellow.auto_border(0.5).get_lcha().0 < light_yellow.get_lcha().0);
+    /// assert!(navy.auto_border(0.5).get_lcha().0 > navy.get_lcha().0);
+    /// ```
+    pub fn auto_border(&self, amount: f64) -> Color {
+        if self.is_light() {
+            self.darken(amount)
+        } else {
+            self.brighten(amount)
+        }
+    }
+
+    /// Gets a color with the hsv value multiplied by a factor, while the hue and saturation are preserved.
+    ///
+    /// The resulting value is clipped to the range from 0.0 to 1.0.
+    /// Unlike multiplying the red, green and blue values, this doesn't shift the hue, if a channel reaches its maximum.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let orange = Color::new_rgb(200, 110, 0);
+    ///
+    /// assert_eq!("#FF8C00", orange.scale_value_hsv(1.5).to_hex_string());
+    /// assert_eq!("#643700", orange.scale_value_hsv(0.5).to_hex_string());
+    /// ```
+    pub fn scale_value_hsv(&self, factor: f64) -> Color {
+        let hsva = self.get_hsva();
+
+        Color::new_hsva(hsva.0, hsva.1, hsva.2 * factor, self.alpha as f64 / 255.0)
+    }
+
+    /// Gets a grayscaled color from the color.
+    ///
+    /// This method uses the default formula used by PAL and NTSC systems ([LumaStandard::Bt601](enum.LumaStandard.html)).  
+    /// `Y = 0.299 * R + 0.587 * G + 0.114 * B`
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("rgb(255, 0, 0)").unwrap();
+    /// let grayscaled_red = red.grayscale();
+    ///
+    /// assert_eq!(76, grayscaled_red.red);
     /// assert_eq!(76, grayscaled_red.green);
     /// assert_eq!(76, grayscaled_red.blue);
     /// assert_eq!(255, grayscaled_red.alpha);
     /// ```
     pub fn grayscale(&self) -> Color {
-        let gray_value = (self.red as f64 * 0.299
-            + self.green as f64 * 0.587
-            + self.blue as f64 * 0.114)
-            .round() as u8;
-        Color {
-            red: gray_value,
-            green: gray_value,
-            blue: gray_value,
-            alpha: self.alpha,
-            ..Default::default()
-        }
+        self.grayscale_weighted(LumaStandard::Bt601)
     }
 
     /// Gets a grayscaled color from the color.
     ///
-    /// This method uses the default formula used by HDTV systems.  
+    /// This method uses the default formula used by HDTV systems ([LumaStandard::Bt709](enum.LumaStandard.html)).  
     /// `Y = 0.2126 * R + 0.7152 * G + 0.0722 * B`
     ///
     /// # Example
@@ -2588,21 +3278,13 @@ impl Color {
     /// assert_eq!(255, grayscaled_red.alpha);
     /// ```
     pub fn grayscale_hdtv(&self) -> Color {
-        let gray_value =
-            (self.red as f64 * 0.2126 + self.green as f64 * 0.7152 + self.blue as f64 * 0.0722)
-                .round() as u8;
-        Color {
-            red: gray_value,
-            green: gray_value,
-            blue: gray_value,
-            alpha: self.alpha,
-            ..Default::default()
-        }
+        self.grayscale_weighted(LumaStandard::Bt709)
     }
 
     /// Gets a grayscaled color from the color.
     ///
-    /// This method uses the default formula used by HDTV systems.  
+    /// This method uses the default formula used by UHDTV and HDR systems ([LumaStandard::Bt2100](enum.LumaStandard.html)),
+    /// like [grayscale_bt2100](#method.grayscale_bt2100).  
     /// `Y = 0.2627 * R + 0.678 * G + 0.0593 * B`
     ///
     /// # Example
@@ -2618,9 +3300,44 @@ impl Color {
     /// assert_eq!(255, grayscaled_red.alpha);
     /// ```
     pub fn grayscale_hdr(&self) -> Color {
-        let gray_value =
-            (self.red as f64 * 0.2627 + self.green as f64 * 0.678 + self.blue as f64 * 0.0593)
-                .round() as u8;
+        self.grayscale_bt2100()
+    }
+
+    /// Gets a grayscaled color from the color.
+    ///
+    /// This method uses the formula of ITU-R BT.2100, as used by UHDTV and HDR systems ([LumaStandard::Bt2100](enum.LumaStandard.html)).  
+    /// `Y = 0.2627 * R + 0.678 * G + 0.0593 * B`
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("rgb(255, 0, 0)").unwrap();
+    ///
+    /// assert_eq!("#434343", red.grayscale_bt2100().to_hex_string());
+    /// ```
+    pub fn grayscale_bt2100(&self) -> Color {
+        self.grayscale_weighted(LumaStandard::Bt2100)
+    }
+
+    /// Gets a grayscaled color from the color, weighted by the luma coefficients of the given standard.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, LumaStandard};
+    ///
+    /// let red = Color::new_string("rgb(255, 0, 0)").unwrap();
+    ///
+    /// assert_eq!("#4C4C4C", red.grayscale_weighted(LumaStandard::Bt601).to_hex_string());
+    /// assert_eq!("#363636", red.grayscale_weighted(LumaStandard::Bt709).to_hex_string());
+    /// assert_eq!("#434343", red.grayscale_weighted(LumaStandard::Bt2100).to_hex_string());
+    /// ```
+    pub fn grayscale_weighted(&self, standard: LumaStandard) -> Color {
+        let weights = standard.weights();
+        let gray_value = (self.red as f64 * weights.0
+            + self.green as f64 * weights.1
+            + self.blue as f64 * weights.2)
+            .round() as u8;
         Color {
             red: gray_value,
             green: gray_value,
@@ -2674,6 +3391,8 @@ impl Color {
 
     /// Gets the inverted color of a color.
     ///
+    /// The alpha value is preserved, only the red, green and blue values are inverted.
+    ///
     /// # Example
     /// ```
     /// use color_processing::Color;
@@ -2682,12 +3401,34 @@ impl Color {
     /// let black_inverted = black.invert();
     ///
     /// assert_eq!("#FFFFFF", black_inverted.to_hex_string());
+    ///
+    /// let transparent_black = Color::new_string("#00000080").unwrap();
+    /// assert_eq!("#FFFFFF80", transparent_black.invert().to_hex_string());
     /// ```
     pub fn invert(&self) -> Color {
+        self.invert_channels(true, true, true)
+    }
+
+    /// Gets the color with only the selected channels inverted.
+    ///
+    /// The channels, that aren't selected, and the alpha value are preserved.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let color = Color::new_string("#102030").unwrap();
+    ///
+    /// assert_eq!("#EF2030", color.invert_channels(true, false, false).to_hex_string());
+    /// assert_eq!("#10DFCF", color.invert_channels(false, true, true).to_hex_string());
+    /// ```
+    pub fn invert_channels(&self, red: bool, green: bool, blue: bool) -> Color {
+        let invert = |value: u8, selected: bool| if selected { 255 - value } else { value };
+
         Color {
-            red: 255 - self.red,
-            green: 255 - self.green,
-            blue: 255 - self.blue,
+            red: invert(self.red, red),
+            green: invert(self.green, green),
+            blue: invert(self.blue, blue),
             alpha: self.alpha,
             ..Default::default()
         }
@@ -2710,6 +3451,151 @@ impl Color {
         Color::new_hsla(hsla.0, hsla.1, 1.0 - hsla.2, hsla.3)
     }
 
+    /// Gets the color as it is seen with the given color vision deficiency.
+    ///
+    /// The simulation uses the matrices by [Machado et al. (2009)](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)
+    /// for the full severity of the deficiency, which are applied to the linear rgb values.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, ColorBlindness};
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let green = Color::new_string("green").unwrap();
+    ///
+    /// let simulated_red = red.simulate_color_blindness(ColorBlindness::Deuteranopia);
+    /// let simulated_green = green.simulate_color_blindness(ColorBlindness::Deuteranopia);
+    ///
+    /// assert!(simulated_red.get_delta_e(&simulated_green) < red.get_delta_e(&green));
+    /// ```
+    pub fn simulate_color_blindness(&self, kind: ColorBlindness) -> Color {
+        let simulated =
+            Color::multiply_matrix(&Color::color_blindness_matrix(kind), self.get_linear_rgb());
+
+        Color::from_linear_rgb(simulated, self.alpha)
+    }
+
+    /// Gets the daltonized color for the given color vision deficiency.
+    ///
+    /// Daltonization shifts the color information, that is lost with the deficiency (see [simulate_color_blindness](#method.simulate_color_blindness)),
+    /// into the channels, that can still be distinguished, so that colors are easier to tell apart.
+    /// The strength goes from 0.0 (the unchanged color) to 1.0 (the full correction).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, ColorBlindness};
+    ///
+    /// let red = Color::new_rgb(255, 0, 0);
+    ///
+    /// assert_eq!(red, red.daltonize(ColorBlindness::Deuteranopia, 0.0));
+    /// assert_ne!(red, red.daltonize(ColorBlindness::Deuteranopia, 1.0));
+    /// ```
+    pub fn daltonize(&self, kind: ColorBlindness, strength: f64) -> Color {
+        let strength = strength.clamp(0.0, 1.0);
+        let rgb = self.get_linear_rgb();
+        let simulated = Color::multiply_matrix(&Color::color_blindness_matrix(kind), rgb);
+        let error = [
+            rgb[0] - simulated[0],
+            rgb[1] - simulated[1],
+            rgb[2] - simulated[2],
+        ];
+        // the error redistribution by Fidaner et al., which shifts the lost information towards green and blue
+        // for the red-green deficiencies and towards red and green for the blue-yellow deficiency.
+        let redistribution = match kind {
+            ColorBlindness::Protanopia | ColorBlindness::Deuteranopia => {
+                [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]]
+            }
+            ColorBlindness::Tritanopia => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+        };
+        let shift = Color::multiply_matrix(&redistribution, error);
+
+        Color::from_linear_rgb(
+            [
+                rgb[0] + shift[0] * strength,
+                rgb[1] + shift[1] * strength,
+                rgb[2] + shift[2] * strength,
+            ],
+            self.alpha,
+        )
+    }
+
+    fn color_blindness_matrix(kind: ColorBlindness) -> [[f64; 3]; 3] {
+        match kind {
+            ColorBlindness::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorBlindness::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorBlindness::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    fn multiply_matrix(matrix: &[[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+        let row = |index: usize| {
+            matrix[index][0] * vector[0]
+                + matrix[index][1] * vector[1]
+                + matrix[index][2] * vector[2]
+        };
+
+        [row(0), row(1), row(2)]
+    }
+
+    fn get_linear_rgb(&self) -> [f64; 3] {
+        [
+            Color::rgb_xyz(self.red),
+            Color::rgb_xyz(self.green),
+            Color::rgb_xyz(self.blue),
+        ]
+    }
+
+    fn from_linear_rgb(rgb: [f64; 3], alpha: u8) -> Color {
+        let channel = |value: f64| {
+            Color::xyz_rgb(value.clamp(0.0, 1.0))
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+
+        Color::new_rgba(channel(rgb[0]), channel(rgb[1]), channel(rgb[2]), alpha)
+    }
+
+    // oklab by Björn Ottosson, see https://bottosson.github.io/posts/oklab/
+    fn get_oklab(&self) -> (f64, f64, f64) {
+        let rgb = self.get_linear_rgb();
+        let l = (0.4122214708 * rgb[0] + 0.5363325363 * rgb[1] + 0.0514459929 * rgb[2]).cbrt();
+        let m = (0.2119034982 * rgb[0] + 0.6806995451 * rgb[1] + 0.1073969566 * rgb[2]).cbrt();
+        let s = (0.0883024619 * rgb[0] + 0.2817188376 * rgb[1] + 0.6299787005 * rgb[2]).cbrt();
+
+        (
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        )
+    }
+
+    fn from_oklab(lightness: f64, a: f64, b: f64, alpha: u8) -> Color {
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        Color::from_linear_rgb(
+            [
+                4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+                -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+                -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+            ],
+            alpha,
+        )
+    }
+
     fn luminance_x(x: u8) -> f64 {
         let x = x as f64 / 255.0;
         if x <= 0.03928 {
@@ -2744,6 +3630,27 @@ impl Color {
         0.2126 * r + 0.7152 * g + 0.0722 * b
     }
 
+    /// Gets the relative luminance of the Color like [get_luminance](#method.get_luminance),
+    /// but the rgb values are decoded with a pure power-law gamma instead of the piecewise srgb curve.
+    ///
+    /// This is useful for displays, which don't follow the srgb standard, e.g. embedded displays with a gamma of 2.2.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let gray = Color::new_rgb(128, 128, 128);
+    ///
+    /// assert_eq!(white.luminance_with_gamma(2.2), 1.0);
+    /// assert_eq!(gray.luminance_with_gamma(1.0), 128.0 / 255.0);
+    /// assert!(gray.luminance_with_gamma(2.2) > gray.get_luminance());
+    /// ```
+    pub fn luminance_with_gamma(&self, gamma: f64) -> f64 {
+        let decode = |channel: u8| (channel as f64 / 255.0).powf(gamma);
+        0.2126 * decode(self.red) + 0.7152 * decode(self.green) + 0.0722 * decode(self.blue)
+    }
+
     /// Computes the [WCAG contrast ratio](https://www.w3.org/TR/2008/REC-WCAG20-20081211/#contrast-ratiodef) between two colors. \
     /// A minimum contrast of 4.5:1 [is recommended](https://www.w3.org/TR/WCAG20-TECHS/G18.html) to ensure that text is still readable against a background color.
     ///
@@ -2768,127 +3675,832 @@ impl Color {
         }
     }
 
-    /// Gets a formatted cmyk String of the color as used in css.
+    /// Checks, if the color is a light color.
+    ///
+    /// A color is considered to be light, if it has a higher [contrast](#method.get_contrast) against black than against white,
+    /// so black text is better readable on it than white text.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
-    /// let red = Color::new_string("red").unwrap();
+    /// let yellow = Color::new_string("yellow").unwrap();
+    /// let navy = Color::new_string("navy").unwrap();
     ///
-    /// assert_eq!("cmyk(0%, 100%, 100%, 0%)", red.to_cmyk_string());
+    /// assert!(yellow.is_light());
+    /// assert!(!navy.is_light());
     /// ```
-    pub fn to_cmyk_string(&self) -> String {
-        let cmyk = self.get_cmyk();
+    pub fn is_light(&self) -> bool {
+        let black = Color::new_rgb(0, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
 
-        format!(
-            "cmyk({}%, {}%, {}%, {}%)",
-            (cmyk.0 * 100.0).round(),
-            (cmyk.1 * 100.0).round(),
-            (cmyk.2 * 100.0).round(),
-            (cmyk.3 * 100.0).round()
-        )
+        self.get_contrast(black) > self.get_contrast(white)
     }
 
-    /// Gets a formatted hex String of the color as used in css.
+    /// Gets the color lightened just enough to reach a target [contrast](#method.get_contrast) against a background color.
+    ///
+    /// Only the lch lightness is increased, the hue is kept and the chroma is reduced as far as needed to stay inside the sRGB gamut.
+    /// Returns `None`, if the target contrast can't be reached, even with the maximum lightness of 100.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
-    /// let red = Color::new_string("red").unwrap();
+    /// let background = Color::new_string("#333333").unwrap();
+    /// let blue = Color::new_string("#3050A0").unwrap();
+    /// let readable_blue = blue.lighten_until_contrast(&background, 4.5).unwrap();
     ///
-    /// assert_eq!("gray(76)", red.to_gray_string());
+    /// assert!(readable_blue.get_contrast(background.clone()) >= 4.5);
+    /// assert_eq!(None, blue.lighten_until_contrast(&background, 15.0));
     /// ```
-    pub fn to_gray_string(&self) -> String {
-        let gray = self.grayscale();
-        let mut gray_string = format!("gray({}", gray.red);
-        if gray.alpha != 255 {
-            gray_string.push_str(format!(", {}", gray.alpha).as_str());
-        }
-        gray_string.push_str(")");
-        gray_string
+    pub fn lighten_until_contrast(&self, background: &Color, target: f64) -> Option<Color> {
+        self.adjust_lightness_until_contrast(background, target, 100.0)
     }
 
-    /// Gets a formatted hex String of the color as used in css.
+    /// Gets the color darkened just enough to reach a target [contrast](#method.get_contrast) against a background color.
+    ///
+    /// Only the lch lightness is decreased, the hue is kept and the chroma is reduced as far as needed to stay inside the sRGB gamut.
+    /// Returns `None`, if the target contrast can't be reached, even with the minimum lightness of 0.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
-    /// let red = Color::new_string("red").unwrap();
-    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    /// let background = Color::new_string("#F0F0F0").unwrap();
+    /// let orange = Color::new_string("orange").unwrap();
+    /// let readable_orange = orange.darken_until_contrast(&background, 4.5).unwrap();
     ///
-    /// assert_eq!("#FF0000", red.to_hex_string());
-    /// assert_eq!("#00FF0080", transparent_green.to_hex_string());
+    /// assert!(readable_orange.get_contrast(background.clone()) >= 4.5);
+    /// assert_eq!(None, orange.darken_until_contrast(&background, 21.0));
     /// ```
-    pub fn to_hex_string(&self) -> String {
-        let mut hex = String::from("#");
-        hex.push_str(format!("{:01$X}", self.red, 2).as_str());
-        hex.push_str(format!("{:01$X}", self.green, 2).as_str());
-        hex.push_str(format!("{:01$X}", self.blue, 2).as_str());
-        if self.alpha != 255 {
-            hex.push_str(format!("{:01$X}", self.alpha, 2).as_str());
+    pub fn darken_until_contrast(&self, background: &Color, target: f64) -> Option<Color> {
+        self.adjust_lightness_until_contrast(background, target, 0.0)
+    }
+
+    fn adjust_lightness_until_contrast(
+        &self,
+        background: &Color,
+        target: f64,
+        final_lightness: f64,
+    ) -> Option<Color> {
+        let contrast = |color: &Color| color.get_contrast(background.clone());
+        if contrast(self) >= target {
+            return Some(self.clone());
         }
-        hex
+
+        let lcha = self.get_lcha();
+        let alpha = self.alpha as f64 / 255.0;
+        let color_with_lightness = |lightness: f64| {
+            let chroma = Color::srgb_gamut_max_chroma(lightness, lcha.1, lcha.2);
+            Color::new_lcha(lightness, chroma, lcha.2, alpha)
+        };
+
+        let final_color = color_with_lightness(final_lightness);
+        if contrast(&final_color) < target {
+            return None;
+        }
+
+        // bisect the lightness between the current (too low contrast) and the final (high enough contrast) value.
+        let mut lower = lcha.0;
+        let mut upper = final_lightness;
+        let mut best_color = final_color;
+        for _ in 0..32 {
+            let middle = (lower + upper) / 2.0;
+            let color = color_with_lightness(middle);
+            if contrast(&color) >= target {
+                upper = middle;
+                best_color = color;
+            } else {
+                lower = middle;
+            }
+        }
+
+        Some(best_color)
     }
 
-    /// Gets a formatted hsl String of the color as used in css.
+    /// Computes the [WCAG contrast ratio](https://www.w3.org/TR/2008/REC-WCAG20-20081211/#contrast-ratiodef) between two colors,
+    /// after both colors are composited over an opaque background color with [blend_over](#method.blend_over).
+    ///
+    /// Unlike [get_contrast](#method.get_contrast), this method takes the alpha values of the colors into account.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
-    /// let red = Color::new_string("red").unwrap();
-    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let transparent_black = Color::new_rgba(0, 0, 0, 26);
     ///
-    /// assert_eq!("hsl(0, 100%, 50%)", red.to_hsl_string());
-    /// assert_eq!("hsla(120, 100%, 50%, 0.5)", transparent_green.to_hsl_string());
+    /// assert_eq!(black.contrast_composited(&white, &white), 21.0);
+    /// assert!(transparent_black.contrast_composited(&white, &white) < 1.5);
     /// ```
-    pub fn to_hsl_string(&self) -> String {
-        let hsla = self.get_hsla();
-        let h_rounded = round_with_precision(hsla.0, 2);
-        let s_rounded = round_with_precision(hsla.1 * 100.0, 2);
-        let l_rounded = round_with_precision(hsla.2 * 100.0, 2);
+    pub fn contrast_composited(&self, color: &Color, over: &Color) -> f64 {
+        self.blend_over(over).get_contrast(color.blend_over(over))
+    }
 
-        let mut hsl_string = String::from("hsl");
-        if self.alpha != 255 {
-            hsl_string.push_str("a");
-        }
-        hsl_string.push_str("(");
-        hsl_string.push_str(format!("{}, {}%, {}%", h_rounded, s_rounded, l_rounded).as_str());
-        if self.alpha != 255 {
-            hsl_string.push_str(format!(", {}", round_with_precision(hsla.3, 2)).as_str());
+    /// Gets the background color with the highest [contrast](#method.get_contrast) against the current (foreground) color,
+    /// e.g. to place text on the most readable surface.
+    ///
+    /// On a tie the first of the candidates wins. If there are no candidates, None is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let backgrounds = [
+    ///     Color::new_string("navy").unwrap(),
+    ///     Color::new_string("yellow").unwrap(),
+    ///     Color::new_string("gray").unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(Some(&backgrounds[0]), white.best_background(&backgrounds));
+    /// ```
+    pub fn best_background<'a>(&self, candidates: &'a [Color]) -> Option<&'a Color> {
+        let mut best: Option<(&Color, f64)> = None;
+        for candidate in candidates {
+            let contrast = self.get_contrast(candidate.clone());
+            if best.is_none_or(|(_, best_contrast)| contrast > best_contrast) {
+                best = Some((candidate, contrast));
+            }
         }
-        hsl_string.push_str(")");
-        hsl_string
+
+        best.map(|(candidate, _)| candidate)
     }
 
-    /// Gets a formatted hsv String of the color as used in css.
+    /// Computes the [CIEDE2000](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) color difference (delta-E) between two colors.
+    /// A value below 1.0 is generally not perceptible by the human eye.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
     /// let red = Color::new_string("red").unwrap();
-    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    /// let dark_red = Color::new_string("darkred").unwrap();
     ///
-    /// assert_eq!("hsv(0, 100%, 100%)", red.to_hsv_string());
-    /// assert_eq!("hsva(120, 100%, 100%, 0.5)", transparent_green.to_hsv_string());
+    /// assert_eq!(red.get_delta_e(&red), 0.0);
+    /// assert_eq!((red.get_delta_e(&dark_red) * 100.0).round() / 100.0, 23.75);
     /// ```
-    pub fn to_hsv_string(&self) -> String {
-        let hsva = self.get_hsva();
-        let h_rounded = round_with_precision(hsva.0, 2);
-        let s_rounded = round_with_precision(hsva.1 * 100.0, 2);
-        let v_rounded = round_with_precision(hsva.2 * 100.0, 2);
+    pub fn get_delta_e(&self, color: &Color) -> f64 {
+        let lab1 = self.get_laba();
+        let lab2 = color.get_laba();
 
-        let mut hsv_string = String::from("hsv");
-        if hsva.3 != 1.0 {
-            hsv_string.push_str("a");
-        }
-        hsv_string.push_str("(");
-        hsv_string.push_str(format!("{}, {}%, {}%", h_rounded, s_rounded, v_rounded).as_str());
-        if hsva.3 != 1.0 {
+        let c1 = (lab1.1 * lab1.1 + lab1.2 * lab1.2).sqrt();
+        let c2 = (lab2.1 * lab2.1 + lab2.2 * lab2.2).sqrt();
+        let c_mean_pow7 = ((c1 + c2) / 2.0).powi(7);
+        let g = 0.5 * (1.0 - (c_mean_pow7 / (c_mean_pow7 + 25.0_f64.powi(7))).sqrt());
+
+        let a1 = lab1.1 * (1.0 + g);
+        let a2 = lab2.1 * (1.0 + g);
+        let c1 = (a1 * a1 + lab1.2 * lab1.2).sqrt();
+        let c2 = (a2 * a2 + lab2.2 * lab2.2).sqrt();
+        let h1 = if c1 == 0.0 {
+            0.0
+        } else {
+            (lab1.2.atan2(a1) * Color::RAD2DEG + 360.0) % 360.0
+        };
+        let h2 = if c2 == 0.0 {
+            0.0
+        } else {
+            (lab2.2.atan2(a2) * Color::RAD2DEG + 360.0) % 360.0
+        };
+
+        let delta_l = lab2.0 - lab1.0;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0 * Color::DEG2RAD).sin();
+
+        let l_mean = (lab1.0 + lab2.0) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * ((h_mean - 30.0) * Color::DEG2RAD).cos()
+            + 0.24 * (2.0 * h_mean * Color::DEG2RAD).cos()
+            + 0.32 * ((3.0 * h_mean + 6.0) * Color::DEG2RAD).cos()
+            - 0.20 * ((4.0 * h_mean - 63.0) * Color::DEG2RAD).cos();
+        let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let c_mean_pow7 = c_mean.powi(7);
+        let r_c = 2.0 * (c_mean_pow7 / (c_mean_pow7 + 25.0_f64.powi(7))).sqrt();
+        let l_mean_50 = (l_mean - 50.0).powi(2);
+        let s_l = 1.0 + 0.015 * l_mean_50 / (20.0 + l_mean_50).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+        let r_t = -(2.0 * delta_theta * Color::DEG2RAD).sin() * r_c;
+
+        let l_term = delta_l / s_l;
+        let c_term = delta_c / s_c;
+        let h_term = delta_h / s_h;
+
+        (l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term).sqrt()
+    }
+
+    /// Gets a report of the differences between the current and another color, e.g. for debugging color transformations.
+    ///
+    /// The channel deltas are signed and computed as `color - self`.
+    /// The hue difference is the shortest signed angle in the lch color space, it's 0.0 if one of the colors has no hue.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let transparent_dark_red = Color::new_rgba(139, 0, 0, 128);
+    /// let diff = red.diff(&transparent_dark_red);
+    ///
+    /// assert_eq!(-116, diff.red);
+    /// assert_eq!(0, diff.green);
+    /// assert_eq!(0, diff.blue);
+    /// assert_eq!(-127, diff.alpha);
+    /// assert_eq!(red.get_delta_e(&transparent_dark_red), diff.delta_e);
+    /// ```
+    pub fn diff(&self, color: &Color) -> ColorDiff {
+        let hue_1 = self.get_lcha().2;
+        let hue_2 = color.get_lcha().2;
+        let hue = if hue_1.is_nan() || hue_2.is_nan() {
+            0.0
+        } else {
+            let delta = (hue_2 - hue_1 + 360.0) % 360.0;
+            if delta > 180.0 {
+                delta - 360.0
+            } else {
+                delta
+            }
+        };
+
+        ColorDiff {
+            red: color.red as i16 - self.red as i16,
+            green: color.green as i16 - self.green as i16,
+            blue: color.blue as i16 - self.blue as i16,
+            alpha: color.alpha as i16 - self.alpha as i16,
+            delta_e: self.get_delta_e(color),
+            hue: round_with_precision(hue, 2),
+        }
+    }
+
+    /// Gets the named sector of the hue wheel, the color belongs to, e.g. for categorizing colors.
+    ///
+    /// The hsl hue is mapped to the sectors with the following boundary angles (each sector includes its lower boundary):
+    ///
+    /// | Sector  | Hue              |
+    /// |---------|------------------|
+    /// | Red     | 345° to 15°      |
+    /// | Orange  | 15° to 45°       |
+    /// | Yellow  | 45° to 75°       |
+    /// | Green   | 75° to 165°      |
+    /// | Cyan    | 165° to 195°     |
+    /// | Blue    | 195° to 255°     |
+    /// | Purple  | 255° to 285°     |
+    /// | Magenta | 285° to 345°     |
+    ///
+    /// Achromatic colors (white, gray, black) don't have a hue and return `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, HueSector};
+    ///
+    /// let orange = Color::new_string("orange").unwrap();
+    /// let gray = Color::new_string("gray").unwrap();
+    ///
+    /// assert_eq!(Some(HueSector::Orange), orange.hue_sector());
+    /// assert_eq!(None, gray.hue_sector());
+    /// ```
+    pub fn hue_sector(&self) -> Option<HueSector> {
+        if self.red == self.green && self.green == self.blue {
+            return None;
+        }
+
+        let hue = self.get_hsla().0;
+        let sector = if hue < 15.0 {
+            HueSector::Red
+        } else if hue < 45.0 {
+            HueSector::Orange
+        } else if hue < 75.0 {
+            HueSector::Yellow
+        } else if hue < 165.0 {
+            HueSector::Green
+        } else if hue < 195.0 {
+            HueSector::Cyan
+        } else if hue < 255.0 {
+            HueSector::Blue
+        } else if hue < 285.0 {
+            HueSector::Purple
+        } else if hue < 345.0 {
+            HueSector::Magenta
+        } else {
+            HueSector::Red
+        };
+
+        Some(sector)
+    }
+
+    /// Sorts colors into a perceptual order, so that neighboring colors look similar, e.g. for laying out swatches.
+    ///
+    /// The order begins with the darkest color and continues with the nearest color (by [get_delta_e](#method.get_delta_e)) each time.
+    /// This is a heuristic, that doesn't always find the optimal order with the smallest total distance.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let mut colors = vec![
+    ///     Color::new_string("white").unwrap(),
+    ///     Color::new_string("black").unwrap(),
+    ///     Color::new_string("darkgray").unwrap(),
+    ///     Color::new_string("dimgray").unwrap(),
+    /// ];
+    /// Color::sort_perceptual(&mut colors);
+    ///
+    /// let names: Vec<String> = colors.iter().map(|c| c.get_original_string()).collect();
+    /// assert_eq!(vec!["black", "dimgray", "darkgray", "white"], names);
+    /// ```
+    pub fn sort_perceptual(colors: &mut [Color]) {
+        if colors.len() < 2 {
+            return;
+        }
+
+        let mut darkest_index = 0;
+        let mut darkest_lightness = f64::MAX;
+        for (index, color) in colors.iter().enumerate() {
+            let lightness = color.get_laba().0;
+            if lightness < darkest_lightness {
+                darkest_index = index;
+                darkest_lightness = lightness;
+            }
+        }
+        colors.swap(0, darkest_index);
+
+        for i in 0..colors.len() - 1 {
+            let mut nearest_index = i + 1;
+            let mut nearest_distance = f64::MAX;
+            for j in i + 1..colors.len() {
+                let distance = colors[i].get_delta_e(&colors[j]);
+                if distance < nearest_distance {
+                    nearest_index = j;
+                    nearest_distance = distance;
+                }
+            }
+            colors.swap(i + 1, nearest_index);
+        }
+    }
+
+    fn srgb_gamut_max_chroma(lightness: f64, chroma: f64, hue: f64) -> f64 {
+        let in_gamut = |c: f64| {
+            let lab = Color::lch_2_lab(lightness, c, hue);
+            let rgb = Color::lab_2_rgb(lab.0, lab.1, lab.2);
+            [rgb.0, rgb.1, rgb.2]
+                .iter()
+                .all(|value| (-0.5..255.5).contains(value))
+        };
+        if in_gamut(chroma) {
+            return chroma;
+        }
+
+        // bisect the chroma between the always valid gray and the requested value.
+        let mut lower = 0.0;
+        let mut upper = chroma;
+        for _ in 0..32 {
+            let middle = (lower + upper) / 2.0;
+            if in_gamut(middle) {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+
+        lower
+    }
+
+    fn cmyk_gamut_max_chroma(lightness: f64, hue: f64) -> f64 {
+        lazy_static! {
+            // (hue, chroma, lightness) of the approximated sRGB values of the process colors (cyan, magenta, yellow)
+            // and their overprints (red, green, blue) on coated paper, sorted by hue and wrapped around the hue circle.
+            static ref CMYK_PRIMARIES: Vec<(f64, f64, f64)> = {
+                let mut primaries: Vec<(f64, f64, f64)> = [
+                    (0x00, 0xAE, 0xEF),
+                    (0xEC, 0x00, 0x8C),
+                    (0xFF, 0xF2, 0x00),
+                    (0xED, 0x1C, 0x24),
+                    (0x00, 0xA6, 0x51),
+                    (0x2E, 0x31, 0x92),
+                ]
+                .iter()
+                .map(|rgb| {
+                    let lcha = Color::new_rgb(rgb.0, rgb.1, rgb.2).get_lcha();
+                    (lcha.2, lcha.1, lcha.0)
+                })
+                .collect();
+                primaries.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let first = primaries[0];
+                let last = primaries[primaries.len() - 1];
+                primaries.insert(0, (last.0 - 360.0, last.1, last.2));
+                primaries.push((first.0 + 360.0, first.1, first.2));
+
+                primaries
+            };
+        }
+
+        // the chroma and lightness of the most saturated printable color (the cusp) of the hue.
+        let mut cusp = (CMYK_PRIMARIES[0].1, CMYK_PRIMARIES[0].2);
+        for window in CMYK_PRIMARIES.windows(2) {
+            let (previous, next) = (window[0], window[1]);
+            if hue >= previous.0 && hue <= next.0 {
+                let factor = (hue - previous.0) / (next.0 - previous.0);
+                cusp = (
+                    previous.1 + (next.1 - previous.1) * factor,
+                    previous.2 + (next.2 - previous.2) * factor,
+                );
+                break;
+            }
+        }
+
+        // the gamut narrows linearly from the cusp down to black and up to the paper white.
+        let (cusp_chroma, cusp_lightness) = cusp;
+        if lightness <= cusp_lightness {
+            cusp_chroma * (lightness / cusp_lightness).max(0.0)
+        } else {
+            cusp_chroma * ((100.0 - lightness) / (100.0 - cusp_lightness)).max(0.0)
+        }
+    }
+
+    /// Gets the color clamped into an approximated cmyk gamut.
+    ///
+    /// The conversion with [get_cmyk](#method.get_cmyk) and [new_cmyk](#method.new_cmyk) is lossless,
+    /// so the printable gamut is approximated by the process colors on coated paper: for each hue, the maximum chroma
+    /// is reached at the lightness of the process colors and decreases linearly towards black and white.
+    /// If the color is outside of this gamut, its lch chroma is reduced, while keeping its lightness and hue.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let blue = Color::new_string("blue").unwrap();
+    /// let gray = Color::new_string("gray").unwrap();
+    ///
+    /// assert!(blue.clamp_to_cmyk_gamut().get_lcha().1 < blue.get_lcha().1);
+    /// assert_eq!(gray, gray.clamp_to_cmyk_gamut());
+    /// ```
+    pub fn clamp_to_cmyk_gamut(&self) -> Color {
+        let lcha = self.get_lcha();
+        if lcha.2.is_nan() {
+            return self.clone();
+        }
+
+        let max_chroma = Color::cmyk_gamut_max_chroma(lcha.0, lcha.2);
+        if lcha.1 <= max_chroma {
+            return self.clone();
+        }
+
+        Color::new_lcha(lcha.0, max_chroma, lcha.2, self.alpha as f64 / 255.0)
+    }
+
+    /// Checks, if the color is reproducible on a generic cmyk device, e.g. for print previews.
+    ///
+    /// The color is printable, if it differs from its [cmyk gamut clamped](#method.clamp_to_cmyk_gamut) variant
+    /// by a [delta-E](#method.get_delta_e) below 2.0, which is barely perceptible.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let blue = Color::new_string("blue").unwrap();
+    /// let dusty_blue = Color::new_rgb(90, 110, 150);
+    ///
+    /// assert!(!blue.is_printable());
+    /// assert!(dusty_blue.is_printable());
+    /// ```
+    pub fn is_printable(&self) -> bool {
+        self.get_delta_e(&self.clamp_to_cmyk_gamut()) < 2.0
+    }
+
+    /// Gets an opaque overlay color, that flags the color as out of the cmyk gamut in print previews.
+    ///
+    /// The overlay color is black or white, whichever has the higher [contrast](#method.get_contrast) against the color,
+    /// so the flagged pixels stand out independent of their own color.
+    /// Use [is_printable](#method.is_printable) to decide, which pixels need to be flagged.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let blue = Color::new_string("blue").unwrap();
+    /// let lime = Color::new_string("lime").unwrap();
+    ///
+    /// assert_eq!("#FFFFFF", blue.gamut_warning_color().to_hex_string());
+    /// assert_eq!("#000000", lime.gamut_warning_color().to_hex_string());
+    /// ```
+    pub fn gamut_warning_color(&self) -> Color {
+        let candidates = [Color::new_rgb(0, 0, 0), Color::new_rgb(255, 255, 255)];
+        let overlay = self.best_background(&candidates).unwrap();
+
+        overlay.clone()
+    }
+
+    /// Gets a vibrant variant of the color, as used for swatches in palette extraction.
+    ///
+    /// The lch chroma is increased by 50% and the lightness is moved into the range from 40 to 70,
+    /// while the hue is preserved. The chroma is reduced as far as needed to stay inside the sRGB gamut,
+    /// but never below the current chroma: if the lightness range can't hold it, the lightness is kept instead.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let dusty_blue = Color::new_rgb(90, 110, 150);
+    /// let vibrant_blue = dusty_blue.vibrant();
+    ///
+    /// assert!(vibrant_blue.get_lcha().1 > dusty_blue.get_lcha().1);
+    /// ```
+    pub fn vibrant(&self) -> Color {
+        let lcha = self.get_lcha();
+        if lcha.2.is_nan() {
+            return self.clone();
+        }
+
+        let mut lightness = lcha.0.clamp(40.0, 70.0);
+        let mut chroma = Color::srgb_gamut_max_chroma(lightness, lcha.1 * 1.5, lcha.2);
+        if chroma < lcha.1 {
+            // light or dark saturated colors (e.g. yellow) would lose chroma in the lightness range,
+            // so their lightness is kept instead.
+            lightness = lcha.0;
+            chroma = Color::srgb_gamut_max_chroma(lightness, lcha.1 * 1.5, lcha.2);
+            if chroma < lcha.1 {
+                // the color is already at the border of the sRGB gamut.
+                return self.clone();
+            }
+        }
+
+        Color::new_lcha(lightness, chroma, lcha.2, self.alpha as f64 / 255.0)
+    }
+
+    /// Gets a muted variant of the color, as used for swatches in palette extraction.
+    ///
+    /// The lch chroma is halved, while the lightness and the hue are preserved.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let muted_red = red.muted();
+    ///
+    /// assert!(muted_red.get_lcha().1 < red.get_lcha().1);
+    /// ```
+    pub fn muted(&self) -> Color {
+        let lcha = self.get_lcha();
+        if lcha.2.is_nan() {
+            return self.clone();
+        }
+
+        let chroma = Color::srgb_gamut_max_chroma(lcha.0, lcha.1 * 0.5, lcha.2);
+
+        Color::new_lcha(lcha.0, chroma, lcha.2, self.alpha as f64 / 255.0)
+    }
+
+    /// Gets the color with a vibrance adjustment, which increases the saturation of muted colors more than of vivid colors.
+    ///
+    /// The hsl saturation is increased by the amount multiplied with the missing saturation (`1.0 - saturation`),
+    /// while the hue and the hsl lightness are preserved. A negative amount decreases the saturation by the amount
+    /// multiplied with the current saturation, so vivid colors lose more saturation than muted colors.
+    /// Achromatic colors are returned unchanged. If `protect_skin` is true, colors with a hue
+    /// in the range of skin tones (from 20 to 40 degrees) are returned unchanged, too.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let dusty_blue = Color::new_rgb(90, 110, 150);
+    /// let vibrant_blue = dusty_blue.vibrance(0.5, true);
+    /// assert!(vibrant_blue.get_hsla().1 > dusty_blue.get_hsla().1);
+    ///
+    /// let skin = Color::new_rgb(224, 172, 138);
+    /// assert_eq!(skin, skin.vibrance(0.5, true));
+    /// ```
+    pub fn vibrance(&self, amount: f64, protect_skin: bool) -> Color {
+        let hsla = self.get_hsla();
+        if hsla.1 == 0.0 || protect_skin && (20.0..=40.0).contains(&hsla.0) {
+            return self.clone();
+        }
+
+        let saturation = if amount < 0.0 {
+            hsla.1 + amount * hsla.1
+        } else {
+            hsla.1 + amount * (1.0 - hsla.1)
+        };
+
+        Color::new_hsla(hsla.0, saturation, hsla.2, self.alpha as f64 / 255.0)
+    }
+
+    /// Gets the color with the lch lightness of a reference color, e.g. for harmonizing the brightness of a palette.
+    ///
+    /// The hue and the chroma of the current color are kept,
+    /// but the chroma is reduced as far as needed to stay inside the sRGB gamut at the new lightness.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let blue = Color::new_string("blue").unwrap();
+    /// let gray = Color::new_string("gray").unwrap();
+    /// let gray_blue = blue.match_lightness(&gray);
+    ///
+    /// assert!((gray_blue.get_lcha().0 - gray.get_lcha().0).abs() < 1.0);
+    /// ```
+    pub fn match_lightness(&self, reference: &Color) -> Color {
+        let lcha = self.get_lcha();
+        let lightness = reference.get_lcha().0;
+        let chroma = Color::srgb_gamut_max_chroma(lightness, lcha.1, lcha.2);
+
+        Color::new_lcha(lightness, chroma, lcha.2, self.alpha as f64 / 255.0)
+    }
+
+    /// Gets a formatted cmyk String of the color as used in css.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    ///
+    /// assert_eq!("cmyk(0%, 100%, 100%, 0%)", red.to_cmyk_string());
+    /// ```
+    pub fn to_cmyk_string(&self) -> String {
+        let cmyk = self.get_cmyk();
+
+        format!(
+            "cmyk({}%, {}%, {}%, {}%)",
+            (cmyk.0 * 100.0).round(),
+            (cmyk.1 * 100.0).round(),
+            (cmyk.2 * 100.0).round(),
+            (cmyk.3 * 100.0).round()
+        )
+    }
+
+    /// Gets a formatted hex String of the color as used in css.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    ///
+    /// assert_eq!("gray(76)", red.to_gray_string());
+    /// ```
+    pub fn to_gray_string(&self) -> String {
+        let gray = self.grayscale();
+        let mut gray_string = format!("gray({}", gray.red);
+        if gray.alpha != 255 {
+            gray_string.push_str(format!(", {}", gray.alpha).as_str());
+        }
+        gray_string.push_str(")");
+        gray_string
+    }
+
+    /// Gets a formatted hex String of the color as used in css.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("#FF0000", red.to_hex_string());
+    /// assert_eq!("#00FF0080", transparent_green.to_hex_string());
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        let mut hex = String::from("#");
+        hex.push_str(format!("{:01$X}", self.red, 2).as_str());
+        hex.push_str(format!("{:01$X}", self.green, 2).as_str());
+        hex.push_str(format!("{:01$X}", self.blue, 2).as_str());
+        if self.alpha != 255 {
+            hex.push_str(format!("{:01$X}", self.alpha, 2).as_str());
+        }
+        hex
+    }
+
+    /// Gets the most compact css String of the color, e.g. for minified css output.
+    ///
+    /// The candidates are a known color name, the 3-digit hex notation (if each channel consists of a repeated digit)
+    /// and the 6-digit hex notation for opaque colors, or the rgba notation without spaces for transparent colors.
+    /// The shortest candidate is returned, on a tie the hex notation wins over the color name (e.g. `#00f` instead of `blue`).
+    /// The hex notation is written in lowercase and the alpha value uses as few decimals as possible, while still parsing back exactly.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// assert_eq!("red", Color::new_rgb(255, 0, 0).to_css_string());
+    /// assert_eq!("#fa0", Color::new_rgb(255, 170, 0).to_css_string());
+    /// assert_eq!("#123456", Color::new_rgb(0x12, 0x34, 0x56).to_css_string());
+    /// assert_eq!("rgba(255,0,0,0.5)", Color::new_rgba(255, 0, 0, 128).to_css_string());
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        let mut css_string = if self.alpha == 255 {
+            let is_collapsible = [self.red, self.green, self.blue]
+                .iter()
+                .all(|value| value >> 4 == value & 0x0F);
+            if is_collapsible {
+                format!(
+                    "#{:x}{:x}{:x}",
+                    self.red & 0x0F,
+                    self.green & 0x0F,
+                    self.blue & 0x0F
+                )
+            } else {
+                format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+            }
+        } else {
+            let mut alpha = round_with_precision(self.alpha as f64 / 255.0, 2);
+            if (alpha * 255.0).round() as u8 != self.alpha {
+                alpha = round_with_precision(self.alpha as f64 / 255.0, 3);
+            }
+            format!("rgba({},{},{},{})", self.red, self.green, self.blue, alpha)
+        };
+
+        for (name, known_color) in Color::KNOWN_COLORS.iter() {
+            if name.len() >= css_string.len() {
+                continue;
+            }
+            if Color::new_enum(*known_color).channels() == self.channels() {
+                css_string = name.to_string();
+            }
+        }
+
+        css_string
+    }
+
+    /// Gets a formatted hsl String of the color as used in css.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("hsl(0, 100%, 50%)", red.to_hsl_string());
+    /// assert_eq!("hsla(120, 100%, 50%, 0.5)", transparent_green.to_hsl_string());
+    /// ```
+    pub fn to_hsl_string(&self) -> String {
+        let hsla = self.get_hsla();
+        let h_rounded = round_with_precision(hsla.0, 2);
+        let s_rounded = round_with_precision(hsla.1 * 100.0, 2);
+        let l_rounded = round_with_precision(hsla.2 * 100.0, 2);
+
+        let mut hsl_string = String::from("hsl");
+        if self.alpha != 255 {
+            hsl_string.push_str("a");
+        }
+        hsl_string.push_str("(");
+        hsl_string.push_str(format!("{}, {}%, {}%", h_rounded, s_rounded, l_rounded).as_str());
+        if self.alpha != 255 {
+            hsl_string.push_str(format!(", {}", round_with_precision(hsla.3, 2)).as_str());
+        }
+        hsl_string.push_str(")");
+        hsl_string
+    }
+
+    /// Gets a formatted hsv String of the color as used in css.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("hsv(0, 100%, 100%)", red.to_hsv_string());
+    /// assert_eq!("hsva(120, 100%, 100%, 0.5)", transparent_green.to_hsv_string());
+    /// ```
+    pub fn to_hsv_string(&self) -> String {
+        let hsva = self.get_hsva();
+        let h_rounded = round_with_precision(hsva.0, 2);
+        let s_rounded = round_with_precision(hsva.1 * 100.0, 2);
+        let v_rounded = round_with_precision(hsva.2 * 100.0, 2);
+
+        let mut hsv_string = String::from("hsv");
+        if self.alpha != 255 {
+            hsv_string.push_str("a");
+        }
+        hsv_string.push_str("(");
+        hsv_string.push_str(format!("{}, {}%, {}%", h_rounded, s_rounded, v_rounded).as_str());
+        if self.alpha != 255 {
             hsv_string.push_str(format!(", {}", round_with_precision(hsva.3, 2)).as_str());
         }
         hsv_string.push_str(")");
@@ -2904,56 +4516,270 @@ impl Color {
     /// let red = Color::new_string("red").unwrap();
     /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
     ///
-    /// assert_eq!("hwb(0, 0%, 0%)", red.to_hwb_string());
-    /// assert_eq!("hwba(120, 0%, 0%, 0.5)", transparent_green.to_hwb_string());
+    /// assert_eq!("hwb(0, 0%, 0%)", red.to_hwb_string());
+    /// assert_eq!("hwba(120, 0%, 0%, 0.5)", transparent_green.to_hwb_string());
+    /// ```
+    pub fn to_hwb_string(&self) -> String {
+        let hwba = self.get_hwba();
+        let h_rounded = hwba.0.round() as u16;
+        let w_rounded = round_with_precision(hwba.1 * 100.0, 2);
+        let b_rounded = round_with_precision(hwba.2 * 100.0, 2);
+
+        let mut hwb_string = String::from("hwb");
+        if self.alpha != 255 {
+            hwb_string.push_str("a");
+        }
+        hwb_string.push_str("(");
+        hwb_string.push_str(format!("{}, {}%, {}%", h_rounded, w_rounded, b_rounded).as_str());
+        if self.alpha != 255 {
+            // round with a precision of 2 decimals.
+            hwb_string.push_str(format!(", {}", round_with_precision(hwba.3, 2)).as_str());
+        }
+        hwb_string.push_str(")");
+        hwb_string
+    }
+
+    /// Gets a formatted rgb String of the color as used in css.
+    ///
+    /// The alpha value is rounded to 2 decimals, so parsing the String doesn't always give back the exact alpha value,
+    /// e.g. an alpha value of 1 (0.004) is printed as 0.
+    /// Use [to_rgb_string_exact](#method.to_rgb_string_exact) for a lossless String.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("rgb(255, 0, 0)", red.to_rgb_string());
+    /// assert_eq!("rgba(0, 255, 0, 0.5)", transparent_green.to_rgb_string());
+    /// ```
+    pub fn to_rgb_string(&self) -> String {
+        let mut rgb = String::from("rgb");
+        if self.alpha != 255 {
+            rgb.push_str("a");
+        }
+        rgb.push_str("(");
+        rgb.push_str(format!("{}, {}, {}", self.red, self.green, self.blue).as_str());
+        if self.alpha != 255 {
+            rgb.push_str(
+                format!(", {}", round_with_precision(self.alpha as f64 / 255.0, 2)).as_str(),
+            );
+        }
+        rgb.push_str(")");
+
+        rgb
+    }
+
+    /// Gets a formatted rgb String of the color in the given css syntax.
+    ///
+    /// With `FormatStyle::Legacy` this is the same as [to_rgb_string](#method.to_rgb_string),
+    /// with `FormatStyle::Css4` the values are separated by spaces and the alpha value by a slash.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, FormatStyle};
+    ///
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("rgba(0, 255, 0, 0.5)", transparent_green.to_rgb_string_styled(FormatStyle::Legacy));
+    /// assert_eq!("rgb(0 255 0 / 0.5)", transparent_green.to_rgb_string_styled(FormatStyle::Css4));
+    /// ```
+    pub fn to_rgb_string_styled(&self, style: FormatStyle) -> String {
+        match style {
+            FormatStyle::Legacy => self.to_rgb_string(),
+            FormatStyle::Css4 => Color::format_css4_function(
+                "rgb",
+                format!("{} {} {}", self.red, self.green, self.blue),
+                self.alpha,
+            ),
+        }
+    }
+
+    /// Gets a formatted hsl String of the color in the given css syntax.
+    ///
+    /// With `FormatStyle::Legacy` this is the same as [to_hsl_string](#method.to_hsl_string),
+    /// with `FormatStyle::Css4` the values are separated by spaces and the alpha value by a slash.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, FormatStyle};
+    ///
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("hsla(120, 100%, 50%, 0.5)", transparent_green.to_hsl_string_styled(FormatStyle::Legacy));
+    /// assert_eq!("hsl(120 100% 50% / 0.5)", transparent_green.to_hsl_string_styled(FormatStyle::Css4));
+    /// ```
+    pub fn to_hsl_string_styled(&self, style: FormatStyle) -> String {
+        match style {
+            FormatStyle::Legacy => self.to_hsl_string(),
+            FormatStyle::Css4 => {
+                let hsla = self.get_hsla();
+                Color::format_css4_function(
+                    "hsl",
+                    format!(
+                        "{} {}% {}%",
+                        round_with_precision(hsla.0, 2),
+                        round_with_precision(hsla.1 * 100.0, 2),
+                        round_with_precision(hsla.2 * 100.0, 2)
+                    ),
+                    self.alpha,
+                )
+            }
+        }
+    }
+
+    /// Gets a formatted hwb String of the color in the given css syntax.
+    ///
+    /// With `FormatStyle::Legacy` this is the same as [to_hwb_string](#method.to_hwb_string),
+    /// with `FormatStyle::Css4` the values are separated by spaces and the alpha value by a slash.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, FormatStyle};
+    ///
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    ///
+    /// assert_eq!("hwba(120, 0%, 0%, 0.5)", transparent_green.to_hwb_string_styled(FormatStyle::Legacy));
+    /// assert_eq!("hwb(120 0% 0% / 0.5)", transparent_green.to_hwb_string_styled(FormatStyle::Css4));
+    /// ```
+    pub fn to_hwb_string_styled(&self, style: FormatStyle) -> String {
+        match style {
+            FormatStyle::Legacy => self.to_hwb_string(),
+            FormatStyle::Css4 => {
+                let hwba = self.get_hwba();
+                Color::format_css4_function(
+                    "hwb",
+                    format!(
+                        "{} {}% {}%",
+                        hwba.0.round() as u16,
+                        round_with_precision(hwba.1 * 100.0, 2),
+                        round_with_precision(hwba.2 * 100.0, 2)
+                    ),
+                    self.alpha,
+                )
+            }
+        }
+    }
+
+    /// Gets a formatted oklab String of the color as used in css.
+    ///
+    /// The lightness, a and b values are printed with a precision of 5 decimals.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let transparent_white = Color::new_rgba(255, 255, 255, 128);
+    ///
+    /// assert_eq!("oklab(0.62796 0.22486 0.12585)", red.to_oklab_string());
+    /// assert_eq!("oklab(1 0 0 / 0.5)", transparent_white.to_oklab_string());
+    /// ```
+    pub fn to_oklab_string(&self) -> String {
+        let oklab = self.get_oklab();
+        // adding 0.0 turns a negative zero into a positive zero, e.g. for the a and b values of grays.
+        let values = format!(
+            "{} {} {}",
+            round_with_precision(oklab.0, 5) + 0.0,
+            round_with_precision(oklab.1, 5) + 0.0,
+            round_with_precision(oklab.2, 5) + 0.0
+        );
+        Color::format_css4_function("oklab", values, self.alpha)
+    }
+
+    /// Gets a formatted oklch String of the color as used in css.
+    ///
+    /// The lightness and the chroma are printed with a precision of 5 decimals and the hue with a precision of 2 decimals.
+    /// Achromatic colors have no hue, so `none` is printed instead.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let gray = Color::new_string("gray").unwrap();
+    ///
+    /// assert_eq!("oklch(0.62796 0.25768 29.23)", red.to_oklch_string());
+    /// assert_eq!("oklch(0.59987 0 none)", gray.to_oklch_string());
+    /// ```
+    pub fn to_oklch_string(&self) -> String {
+        let oklab = self.get_oklab();
+        let lightness = round_with_precision(oklab.0, 5) + 0.0;
+        let chroma = round_with_precision((oklab.1 * oklab.1 + oklab.2 * oklab.2).sqrt(), 5);
+        let values = if chroma == 0.0 {
+            format!("{} 0 none", lightness)
+        } else {
+            let hue = (oklab.2.atan2(oklab.1) * Color::RAD2DEG + 360.0) % 360.0;
+            format!("{} {} {}", lightness, chroma, round_with_precision(hue, 2))
+        };
+        Color::format_css4_function("oklch", values, self.alpha)
+    }
+
+    fn format_css4_function(css_function: &str, values: String, alpha: u8) -> String {
+        if alpha == 255 {
+            format!("{}({})", css_function, values)
+        } else {
+            format!(
+                "{}({} / {})",
+                css_function,
+                values,
+                round_with_precision(alpha as f64 / 255.0, 2)
+            )
+        }
+    }
+
+    /// Gets a formatted rgb String of the color as used in css, that always parses back to the exact same color.
+    ///
+    /// Unlike [to_rgb_string](#method.to_rgb_string), the alpha value is printed with a precision of 3 decimals,
+    /// which is enough to distinguish all 256 alpha values.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let almost_transparent_red = Color::new_rgba(255, 0, 0, 1);
+    /// let rgb_string = almost_transparent_red.to_rgb_string_exact();
+    ///
+    /// assert_eq!("rgba(255, 0, 0, 0.004)", rgb_string);
+    /// assert_eq!(1, Color::new_string(rgb_string).unwrap().alpha);
     /// ```
-    pub fn to_hwb_string(&self) -> String {
-        let hwba = self.get_hwba();
-        let h_rounded = hwba.0.round() as u16;
-        let w_rounded = round_with_precision(hwba.1 * 100.0, 2);
-        let b_rounded = round_with_precision(hwba.2 * 100.0, 2);
-
-        let mut hwb_string = String::from("hwb");
-        if self.alpha != 255 {
-            hwb_string.push_str("a");
-        }
-        hwb_string.push_str("(");
-        hwb_string.push_str(format!("{}, {}%, {}%", h_rounded, w_rounded, b_rounded).as_str());
-        if self.alpha != 255 {
-            // round with a precision of 2 decimals.
-            hwb_string.push_str(format!(", {}", round_with_precision(hwba.3, 2)).as_str());
+    pub fn to_rgb_string_exact(&self) -> String {
+        if self.alpha == 255 {
+            return self.to_rgb_string();
         }
-        hwb_string.push_str(")");
-        hwb_string
+
+        format!(
+            "rgba({}, {}, {}, {})",
+            self.red,
+            self.green,
+            self.blue,
+            round_with_precision(self.alpha as f64 / 255.0, 3)
+        )
     }
 
-    /// Gets a formatted rgb String of the color as used in css.
+    /// Gets a block of `width` spaces with the color as ANSI truecolor background, followed by a reset sequence.
+    ///
+    /// Printing several of these blocks in a row draws a palette strip in a terminal.
+    /// The alpha value is ignored, because terminals don't support transparent colors.
     ///
     /// # Example
     /// ```
     /// use color_processing::Color;
     ///
     /// let red = Color::new_string("red").unwrap();
-    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
     ///
-    /// assert_eq!("rgb(255, 0, 0)", red.to_rgb_string());
-    /// assert_eq!("rgba(0, 255, 0, 0.5)", transparent_green.to_rgb_string());
+    /// assert_eq!("\x1b[48;2;255;0;0m   \x1b[0m", red.to_ansi_block(3));
     /// ```
-    pub fn to_rgb_string(&self) -> String {
-        let mut rgb = String::from("rgb");
-        if self.alpha != 255 {
-            rgb.push_str("a");
-        }
-        rgb.push_str("(");
-        rgb.push_str(format!("{}, {}, {}", self.red, self.green, self.blue).as_str());
-        if self.alpha != 255 {
-            rgb.push_str(
-                format!(", {}", round_with_precision(self.alpha as f64 / 255.0, 2)).as_str(),
-            );
-        }
-        rgb.push_str(")");
-
-        rgb
+    pub fn to_ansi_block(&self, width: usize) -> String {
+        format!(
+            "\x1b[48;2;{};{};{}m{}\x1b[0m",
+            self.red,
+            self.green,
+            self.blue,
+            " ".repeat(width)
+        )
     }
 
     /// Converts the Color-struct to an i32 number.  
@@ -2977,6 +4803,84 @@ impl Color {
         numbered_color
     }
 
+    /// Converts the Color-struct to an u32 number in the `0xAARRGGBB` layout.
+    /// The value has the same bits as [to_number](#method.to_number), but without the sign, e.g. `to_number() as u32 == to_argb_u32()`.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    ///
+    /// assert_eq!(0x80FF0000, transparent_red.to_argb_u32());
+    /// assert_eq!(transparent_red.to_number() as u32, transparent_red.to_argb_u32());
+    /// ```
+    pub fn to_argb_u32(&self) -> u32 {
+        u32::from_be_bytes([self.alpha, self.red, self.green, self.blue])
+    }
+
+    /// Converts the Color-struct to an u32 number in the `0xRRGGBBAA` layout, as used in the css hex notation `#RRGGBBAA`.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    ///
+    /// assert_eq!(0xFF000080, transparent_red.to_rgba_u32());
+    /// ```
+    pub fn to_rgba_u32(&self) -> u32 {
+        u32::from_be_bytes([self.red, self.green, self.blue, self.alpha])
+    }
+
+    /// Converts the Color-struct to an u32 number, whose bytes are in the given channel order (from the most to the least significant byte).
+    /// [ChannelOrder::Argb](enum.ChannelOrder.html) gives the same value as [to_argb_u32](#method.to_argb_u32)
+    /// and [ChannelOrder::Rgba](enum.ChannelOrder.html) the same value as [to_rgba_u32](#method.to_rgba_u32).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{ChannelOrder, Color};
+    ///
+    /// let color = Color::new_rgba(0x12, 0x34, 0x56, 0x80);
+    ///
+    /// assert_eq!(0x80563412, color.to_packed_u32(ChannelOrder::Abgr));
+    /// assert_eq!(0x56341280, color.to_packed_u32(ChannelOrder::Bgra));
+    /// ```
+    pub fn to_packed_u32(&self, order: ChannelOrder) -> u32 {
+        let bytes = match order {
+            ChannelOrder::Argb => [self.alpha, self.red, self.green, self.blue],
+            ChannelOrder::Rgba => [self.red, self.green, self.blue, self.alpha],
+            ChannelOrder::Abgr => [self.alpha, self.blue, self.green, self.red],
+            ChannelOrder::Bgra => [self.blue, self.green, self.red, self.alpha],
+        };
+        u32::from_be_bytes(bytes)
+    }
+
+    /// Gets a key for grouping similar colors into coarse buckets, e.g. for a color histogram.
+    ///
+    /// Each of the red, green and blue values is quantized to its `bits_per_channel` most significant bits
+    /// and the results are packed into the key as `red << (2 * bits) | green << bits | blue`.
+    /// The alpha value is ignored and `bits_per_channel` values above 8 are treated as 8.
+    /// Colors with the same key are in the same bucket.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_rgb(255, 0, 0);
+    /// let dark_red = Color::new_rgb(250, 5, 5);
+    ///
+    /// assert_eq!(0xF00, red.bucket_key(4));
+    /// assert_eq!(red.bucket_key(4), dark_red.bucket_key(4));
+    /// assert_ne!(red.bucket_key(8), dark_red.bucket_key(8));
+    /// ```
+    pub fn bucket_key(&self, bits_per_channel: u8) -> u32 {
+        let bits = bits_per_channel.min(8) as u32;
+        let quantize = |value: u8| value as u32 >> (8 - bits);
+
+        (quantize(self.red) << (2 * bits)) | (quantize(self.green) << bits) | quantize(self.blue)
+    }
+
     /// Converts the Color-struct to an u16 number, that represents the color-temperature.  
     ///
     /// # Example
@@ -3016,6 +4920,9 @@ impl Color {
     /// Gets an interpolated Color-struct from the current to the final color by an interpolation factor.
     /// The interpolation is made by the rgb values.
     ///
+    /// The rgb values are gamma encoded, so the interpolated colors in the midtones are darker than expected.
+    /// For blending colors, please consider [interpolate_linear](#method.interpolate_linear).
+    ///
     /// # Example
     /// ```
     /// use color_processing::Color;
@@ -3047,6 +4954,87 @@ impl Color {
         }
     }
 
+    /// Gets a number of evenly spaced interpolated colors from the current to the final color, e.g. for large gradients.
+    /// The interpolation is made by the rgb values, like in [interpolate](#method.interpolate).
+    ///
+    /// The first and the last color are always the current and the final color.
+    /// A count of 1 only returns the current color and a count of 0 returns an empty Vec.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let grays = white.interpolate_many(&black, 3);
+    ///
+    /// assert_eq!(3, grays.len());
+    /// assert_eq!("rgb(255, 255, 255)", grays[0].to_rgb_string());
+    /// assert_eq!("rgb(128, 128, 128)", grays[1].to_rgb_string());
+    /// assert_eq!("rgb(0, 0, 0)", grays[2].to_rgb_string());
+    /// ```
+    pub fn interpolate_many(&self, other: &Color, count: usize) -> Vec<Color> {
+        let start = [
+            self.red as f64,
+            self.green as f64,
+            self.blue as f64,
+            self.alpha as f64,
+        ];
+        let deltas = [
+            other.red as f64 - start[0],
+            other.green as f64 - start[1],
+            other.blue as f64 - start[2],
+            other.alpha as f64 - start[3],
+        ];
+        let steps = if count > 1 { (count - 1) as f64 } else { 1.0 };
+
+        (0..count)
+            .map(|index| {
+                let i = index as f64 / steps;
+                Color {
+                    red: (start[0] + deltas[0] * i).round() as u8,
+                    green: (start[1] + deltas[1] * i).round() as u8,
+                    blue: (start[2] + deltas[2] * i).round() as u8,
+                    alpha: (start[3] + deltas[3] * i).round() as u8,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    /// Gets an interpolated Color-struct from the current to the final color by an interpolation factor.
+    /// The interpolation is made by the linear (gamma decoded) rgb values.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let gray = white.interpolate_linear(black, 0.5);
+    ///
+    /// assert_eq!("rgb(188, 188, 188)", gray.to_rgb_string());
+    /// ```
+    pub fn interpolate_linear(&self, color: Color, interpolation: f64) -> Color {
+        let i = interpolation.clamp(0.0, 1.0);
+
+        let interpolate_channel = |value: u8, final_value: u8| -> u8 {
+            let linear_value = Color::rgb_xyz(value);
+            let final_linear_value = Color::rgb_xyz(final_value);
+            Color::xyz_rgb(linear_value + (final_linear_value - linear_value) * i)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+
+        Color {
+            red: interpolate_channel(self.red, color.red),
+            green: interpolate_channel(self.green, color.green),
+            blue: interpolate_channel(self.blue, color.blue),
+            alpha: (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * i).round() as u8,
+            ..Default::default()
+        }
+    }
+
     /// Gets an interpolated Color-struct from the current to the final color by an interpolation factor.
     /// The interpolation is made by the hsv values.
     ///
@@ -3082,7 +5070,7 @@ impl Color {
         let new_h = first_h + (second_h - first_h) * i;
         let new_s = first_s + (second_s - first_s) * i;
         let new_v = first_v + (second_v - first_v) * i;
-        let new_a = self.alpha as f64 + (color.alpha as i16 - self.alpha as i16) as f64 * i / 255.0;
+        let new_a = (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * i) / 255.0;
 
         Color::new_hsva(new_h, new_s, new_v, new_a)
     }
@@ -3122,7 +5110,7 @@ impl Color {
         let new_h = first_h + (second_h - first_h) * i;
         let new_s = first_s + (second_s - first_s) * i;
         let new_l = first_l + (second_l - first_l) * i;
-        let new_a = self.alpha as f64 + (color.alpha as i16 - self.alpha as i16) as f64 * i / 255.0;
+        let new_a = (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * i) / 255.0;
 
         Color::new_hsla(new_h, new_s, new_l, new_a)
     }
@@ -3154,73 +5142,413 @@ impl Color {
         let first_w = hwba.1;
         let first_b = hwba.2;
 
-        let second_hwba = color.get_hwba();
-        let second_h = second_hwba.0;
-        let second_w = second_hwba.1;
-        let second_b = second_hwba.2;
+        let second_hwba = color.get_hwba();
+        let second_h = second_hwba.0;
+        let second_w = second_hwba.1;
+        let second_b = second_hwba.2;
+
+        let new_h = first_h + (second_h - first_h) * interpolation;
+        let new_s = first_w + (second_w - first_w) * interpolation;
+        let new_l = first_b + (second_b - first_b) * interpolation;
+        let new_a = (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * i) / 255.0;
+
+        Color::new_hwba(new_h, new_s, new_l, new_a)
+    }
+
+    /// Gets an interpolated Color-struct from the current to the final color by an interpolation factor.
+    /// The interpolation is made by the lch values.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let gray = white.interpolate_lch(black, 0.5);
+    ///
+    /// assert_eq!("rgb(119, 119, 119)", gray.to_rgb_string());
+    /// ```
+    pub fn interpolate_lch(&self, color: Color, interpolation: f64) -> Color {
+        let i = if interpolation < 0.0 {
+            0.0
+        } else if interpolation > 1.0 {
+            1.0
+        } else {
+            interpolation
+        };
+
+        let lch = self.get_lcha();
+        let first_l = lch.0;
+        let first_c = lch.1;
+        let first_h = lch.2;
+
+        let second_lch = color.get_lcha();
+        let second_l = second_lch.0;
+        let second_c = second_lch.1;
+        let second_h = second_lch.2;
+
+        let new_h = if !first_h.is_nan() && !second_h.is_nan() {
+            let dh = if second_h > first_h && second_h - first_h > 180.0 {
+                second_h - (first_h + 360.0)
+            } else if second_h < first_h && first_h - second_h > 180.0 {
+                second_h + 360.0 - first_h
+            } else {
+                second_h - first_h
+            };
+            first_h + i * dh
+        } else if !first_h.is_nan() {
+            first_h
+        } else if !second_h.is_nan() {
+            second_h
+        } else {
+            std::f64::NAN
+        };
+
+        let new_l = first_l + (second_l - first_l) * i;
+        let new_c = first_c + (second_c - first_c) * i;
+        let new_a = (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * i) / 255.0;
+
+        Color::new_lcha(new_l, new_c, new_h, new_a)
+    }
+
+    /// Gets an interpolated Color-struct from the current to the final color by an interpolation factor.
+    /// The interpolation is made by the lab values.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let gray = white.interpolate_lab(black, 0.5);
+    ///
+    /// assert_eq!("rgb(119, 119, 119)", gray.to_rgb_string());
+    /// ```
+    pub fn interpolate_lab(&self, color: Color, interpolation: f64) -> Color {
+        let i = interpolation.clamp(0.0, 1.0);
+
+        let lab = self.get_laba();
+        let second_lab = color.get_laba();
+
+        let new_l = lab.0 + (second_lab.0 - lab.0) * i;
+        let new_a = lab.1 + (second_lab.1 - lab.1) * i;
+        let new_b = lab.2 + (second_lab.2 - lab.2) * i;
+        let new_alpha = (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * i) / 255.0;
+
+        Color::new_laba(new_l, new_a, new_b, new_alpha)
+    }
+
+    /// Mixes the current color with another color in the given color space.
+    ///
+    /// The weight goes from 0.0 (only the current color) to 1.0 (only the other color),
+    /// values out of this range are clamped (see [try_mix](#method.try_mix) for a checked variant).
+    /// This is a shortcut to the interpolate-methods, e.g. `MixSpace::Hsl` uses [interpolate_hsl](#method.interpolate_hsl).
+    /// Independent of the color space, the alpha value is always interpolated linearly.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, MixSpace};
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    ///
+    /// assert_eq!("rgb(128, 128, 128)", white.mix(&black, 0.5, MixSpace::Rgb).to_rgb_string());
+    /// assert_eq!("rgb(119, 119, 119)", white.mix(&black, 0.5, MixSpace::Lab).to_rgb_string());
+    /// ```
+    pub fn mix(&self, color: &Color, weight: f64, space: MixSpace) -> Color {
+        match space {
+            MixSpace::Rgb => self.interpolate(color.clone(), weight),
+            MixSpace::Hsl => self.interpolate_hsl(color.clone(), weight),
+            MixSpace::Hsv => self.interpolate_hsv(color.clone(), weight),
+            MixSpace::Hwb => self.interpolate_hwb(color.clone(), weight),
+            MixSpace::Lab => self.interpolate_lab(color.clone(), weight),
+            MixSpace::Lch => self.interpolate_lch(color.clone(), weight),
+        }
+    }
+
+    /// Mixes the current color with another color in the given color space, like [mix](#method.mix),
+    /// but returns an error instead of clamping the weight.
+    ///
+    /// The weight must be in the range from 0.0 to 1.0, otherwise (or if it's NaN) a [ParseError](struct.ParseError.html)
+    /// with the reason `ParseErrorEnum::InvalidWeight` is returned, as the crate has no separate error type for arguments.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, MixSpace, ParseErrorEnum};
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    ///
+    /// let gray = white.try_mix(&black, 0.5, MixSpace::Rgb).unwrap();
+    /// assert_eq!("rgb(128, 128, 128)", gray.to_rgb_string());
+    ///
+    /// let error = white.try_mix(&black, 1.5, MixSpace::Rgb).unwrap_err();
+    /// assert_eq!(ParseErrorEnum::InvalidWeight, error.reason);
+    /// ```
+    pub fn try_mix(
+        &self,
+        other: &Color,
+        weight: f64,
+        space: MixSpace,
+    ) -> Result<Color, ParseError> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(ParseError {
+                reason: ParseErrorEnum::InvalidWeight,
+            });
+        }
+
+        Ok(self.mix(other, weight, space))
+    }
+
+    /// Mixes the current color with another color in every supported color space, e.g. to compare the results side by side.
+    ///
+    /// The returned map contains an entry for each MixSpace with the same result as [mix](#method.mix).
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{Color, MixSpace};
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let mixes = white.mix_all_spaces(&black, 0.5);
+    ///
+    /// assert_eq!(6, mixes.len());
+    /// assert_eq!("rgb(128, 128, 128)", mixes[&MixSpace::Rgb].to_rgb_string());
+    /// assert_eq!("rgb(119, 119, 119)", mixes[&MixSpace::Lab].to_rgb_string());
+    /// ```
+    pub fn mix_all_spaces(&self, other: &Color, t: f64) -> HashMap<MixSpace, Color> {
+        [
+            MixSpace::Rgb,
+            MixSpace::Hsl,
+            MixSpace::Hsv,
+            MixSpace::Hwb,
+            MixSpace::Lab,
+            MixSpace::Lch,
+        ]
+        .into_iter()
+        .map(|space| (space, self.mix(other, t, space)))
+        .collect()
+    }
+
+    /// Mixes the current color with another color in the given color space and alpha mode.
+    ///
+    /// With `AlphaMode::Straight` the color channels are mixed independently of the alpha values, like [mix](#method.mix) does.
+    /// With `AlphaMode::Premultiplied` each color contributes to the mixed channels in proportion to its alpha value,
+    /// so a fully transparent color doesn't tint the result. In both modes the alpha value itself is mixed linearly.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::{AlphaMode, Color, MixSpace};
+    ///
+    /// let transparent_red = Color::new_rgba(255, 0, 0, 0);
+    /// let blue = Color::new_string("blue").unwrap();
+    ///
+    /// let straight = transparent_red.mix_with_alpha_mode(&blue, 0.5, MixSpace::Rgb, AlphaMode::Straight);
+    /// assert_eq!("rgba(128, 0, 128, 0.5)", straight.to_rgb_string());
+    ///
+    /// let premultiplied = transparent_red.mix_with_alpha_mode(&blue, 0.5, MixSpace::Rgb, AlphaMode::Premultiplied);
+    /// assert_eq!("rgba(0, 0, 255, 0.5)", premultiplied.to_rgb_string());
+    /// ```
+    pub fn mix_with_alpha_mode(
+        &self,
+        color: &Color,
+        weight: f64,
+        space: MixSpace,
+        alpha_mode: AlphaMode,
+    ) -> Color {
+        let w = weight.clamp(0.0, 1.0);
+        let channel_weight = match alpha_mode {
+            AlphaMode::Straight => w,
+            AlphaMode::Premultiplied => {
+                let alpha_1 = self.alpha as f64 / 255.0;
+                let alpha_2 = color.alpha as f64 / 255.0;
+                let total_alpha = (1.0 - w) * alpha_1 + w * alpha_2;
+                if total_alpha > 0.0 {
+                    w * alpha_2 / total_alpha
+                } else {
+                    w
+                }
+            }
+        };
+
+        let mut mixed_color = self.mix(color, channel_weight, space);
+        mixed_color.alpha =
+            (self.alpha as f64 + (color.alpha as f64 - self.alpha as f64) * w).round() as u8;
+
+        mixed_color
+    }
+
+    /// Gets the perceptual midpoint between the current and another color.
+    ///
+    /// This is the perceptual counterpart to `interpolate(color, 0.5)`, the colors are mixed in the lab color space.
+    /// The result is not necessarily lighter than the sRGB midpoint: the midpoint of black and white has a lab lightness of 50 (`#777777`),
+    /// which is slightly darker than `#808080`, because the gamma encoded sRGB values are already close to perceptually uniform.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let white = Color::new_string("white").unwrap();
+    /// let black = Color::new_string("black").unwrap();
+    /// let gray = white.perceptual_midpoint(&black);
+    ///
+    /// assert_eq!("#777777", gray.to_hex_string());
+    /// ```
+    pub fn perceptual_midpoint(&self, color: &Color) -> Color {
+        self.mix(color, 0.5, MixSpace::Lab)
+    }
+
+    /// Gets a single color, that represents a gradient of evenly spaced color stops, e.g. for a thumbnail or a fallback background.
+    ///
+    /// The gradient is interpolated in the lab color space (like [interpolate_lab](#method.interpolate_lab))
+    /// and the result is the perceptual average over the whole gradient.
+    /// Returns `None`, if there are no color stops.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let red = Color::new_string("red").unwrap();
+    /// let blue = Color::new_string("blue").unwrap();
+    /// let representative = Color::dominant_from_gradient(&[red.clone(), blue.clone()]).unwrap();
+    ///
+    /// assert_eq!(representative, red.perceptual_midpoint(&blue));
+    /// assert_eq!(None, Color::dominant_from_gradient(&[]));
+    /// ```
+    pub fn dominant_from_gradient(colors: &[Color]) -> Option<Color> {
+        if colors.len() < 2 {
+            return colors
+                .first()
+                .map(|color| Color::new_rgba(color.red, color.green, color.blue, color.alpha));
+        }
 
-        let new_h = first_h + (second_h - first_h) * interpolation;
-        let new_s = first_w + (second_w - first_w) * interpolation;
-        let new_l = first_b + (second_b - first_b) * interpolation;
-        let new_a = self.alpha as f64 + (color.alpha as i16 - self.alpha as i16) as f64 * i / 255.0;
+        // the lab values change linearly along each segment, so its average is the midpoint of the segment.
+        let segments = (colors.len() - 1) as f64;
+        let mut sum = (0.0, 0.0, 0.0, 0.0);
+        for window in colors.windows(2) {
+            let laba_1 = window[0].get_laba();
+            let laba_2 = window[1].get_laba();
+            sum.0 += (laba_1.0 + laba_2.0) / 2.0;
+            sum.1 += (laba_1.1 + laba_2.1) / 2.0;
+            sum.2 += (laba_1.2 + laba_2.2) / 2.0;
+            sum.3 += (window[0].alpha as f64 + window[1].alpha as f64) / 2.0;
+        }
 
-        Color::new_hwba(new_h, new_s, new_l, new_a)
+        Some(Color::new_laba(
+            sum.0 / segments,
+            sum.1 / segments,
+            sum.2 / segments,
+            sum.3 / segments / 255.0,
+        ))
     }
 
-    /// Gets an interpolated Color-struct from the current to the final color by an interpolation factor.
-    /// The interpolation is made by the lch values.
+    /// Gets a number of evenly spaced colors along a gradient through the given key colors.
+    ///
+    /// The key colors are evenly spaced along the gradient and consecutive key colors are mixed
+    /// in the given color space (like [mix](#method.mix)).
+    /// Samples, which fall exactly on a key color, return the key color itself.
+    /// Returns an empty Vec, if there are no key colors or the count is 0.
     ///
     /// # Example
     /// ```
-    /// use color_processing::Color;
+    /// use color_processing::{Color, MixSpace};
     ///
     /// let white = Color::new_string("white").unwrap();
     /// let black = Color::new_string("black").unwrap();
-    /// let gray = white.interpolate_lch(black, 0.5);
+    /// let grays = Color::gradient_through(&[white, black], 3, MixSpace::Lab);
     ///
-    /// assert_eq!("rgb(119, 119, 119)", gray.to_rgb_string());
+    /// assert_eq!(3, grays.len());
+    /// assert_eq!("#FFFFFF", grays[0].to_hex_string());
+    /// assert_eq!("#777777", grays[1].to_hex_string());
+    /// assert_eq!("#000000", grays[2].to_hex_string());
     /// ```
-    pub fn interpolate_lch(&self, color: Color, interpolation: f64) -> Color {
-        let i = if interpolation < 0.0 {
-            0.0
-        } else if interpolation > 1.0 {
-            1.0
-        } else {
-            interpolation
-        };
+    pub fn gradient_through(colors: &[Color], n: usize, space: MixSpace) -> Vec<Color> {
+        if colors.is_empty() {
+            return Vec::new();
+        }
 
-        let lch = self.get_lcha();
-        let first_l = lch.0;
-        let first_c = lch.1;
-        let first_h = lch.2;
+        let segments = colors.len() - 1;
+        let steps = if n > 1 { (n - 1) as f64 } else { 1.0 };
+        (0..n)
+            .map(|index| {
+                let position = (index * segments) as f64 / steps;
+                let segment = (position.floor() as usize).min(segments);
+                let weight = position - segment as f64;
+                let color = &colors[segment];
+                if weight == 0.0 {
+                    Color::new_rgba(color.red, color.green, color.blue, color.alpha)
+                } else {
+                    color.mix(&colors[segment + 1], weight, space)
+                }
+            })
+            .collect()
+    }
 
-        let second_lch = color.get_lcha();
-        let second_l = second_lch.0;
-        let second_c = second_lch.1;
-        let second_h = second_lch.2;
+    fn try_parse_normalized(
+        normalized_str: &str,
+        allow_mixed_units: bool,
+    ) -> Result<Color, ParseErrorEnum> {
+        if normalized_str.len() == 0 {
+            return Err(ParseErrorEnum::EmptyString);
+        }
 
-        let new_h = if !first_h.is_nan() && !second_h.is_nan() {
-            let dh = if second_h > first_h && second_h - first_h > 180.0 {
-                second_h - (first_h + 360.0)
-            } else if second_h < first_h && first_h - second_h > 180.0 {
-                second_h + 360.0 - first_h
-            } else {
-                second_h - first_h
-            };
-            first_h + i * dh
-        } else if !first_h.is_nan() {
-            first_h
-        } else if !second_h.is_nan() {
-            second_h
-        } else {
-            std::f64::NAN
-        };
+        if normalized_str.len() == 2 {
+            match Color::try_parse_abbr_color(normalized_str) {
+                Some(color) => {
+                    return Ok(color);
+                }
+                None => {
+                    return Err(ParseErrorEnum::InvalidAbbreviation);
+                }
+            }
+        }
 
-        let new_l = first_l + (second_l - first_l) * i;
-        let new_c = first_c + (second_c - first_c) * i;
-        let new_a = self.alpha as f64 + (color.alpha as i16 - self.alpha as i16) as f64 * i / 255.0;
+        let first_char = normalized_str.chars().nth(0).unwrap();
+        let invalid_hex_char_position =
+            normalized_str.find(|c| c < '0' || c > '9' && c < 'a' || c > 'f');
+        if first_char == '#' || invalid_hex_char_position.is_none() {
+            match Color::try_parse_hex(normalized_str) {
+                Some(color) => {
+                    return Ok(color);
+                }
+                None => {
+                    return Err(ParseErrorEnum::InvalidHexValue);
+                }
+            }
+        }
 
-        Color::new_lcha(new_l, new_c, new_h, new_a)
+        match normalized_str.find('(') {
+            Some(parentheses_position) => {
+                if parentheses_position > 0 {
+                    match Color::try_parse_css_function(normalized_str, allow_mixed_units) {
+                        Ok(color) => {
+                            return Ok(color);
+                        }
+                        Err(reason) => {
+                            return Err(reason);
+                        }
+                    }
+                } else {
+                    return Err(ParseErrorEnum::Unknown);
+                }
+            }
+            None => {}
+        }
+
+        if normalized_str.find(|c| c < 'a' || c > 'z').is_none() {
+            match Color::try_parse_known_color(normalized_str)
+                .or_else(|| Color::try_parse_system_color(normalized_str))
+            {
+                Some(color) => {
+                    return Ok(color);
+                }
+                None => {
+                    return Err(ParseErrorEnum::InvalidColorName);
+                }
+            }
+        }
+
+        return Err(ParseErrorEnum::Unknown);
     }
 
     fn try_parse_hex(string: &str) -> Option<Color> {
@@ -3287,12 +5615,53 @@ impl Color {
         }
     }
 
-    fn try_parse_css_function(string: &str) -> Option<Color> {
+    fn try_parse_css_function_without_parentheses(
+        string: &str,
+        allow_mixed_units: bool,
+    ) -> Option<Result<Color, ParseErrorEnum>> {
+        lazy_static! {
+            // cap[1] -> css-function
+            // cap[2] -> space or comma separated values
+            static ref RE_CSS_FUNCTION_WITHOUT_PARENTHESES: Regex = Regex::new(r"^(cmyk|gray|grey|hsla?|hsva?|hwba?|lab|oklab|oklch|rgba?)\s+([^()]+)$").unwrap();
+        }
+        let cap = RE_CSS_FUNCTION_WITHOUT_PARENTHESES.captures(string)?;
+        let values: Vec<&str> = cap[2]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|value| !value.is_empty())
+            .collect();
+        let css_function = if matches!(&cap[1], "lab" | "oklab" | "oklch") {
+            // these functions only support the css4 syntax, where the alpha value is separated by a slash.
+            let (components, alpha) = values.split_at(values.len().min(3));
+            if alpha.is_empty() {
+                format!("{}({})", &cap[1], components.join(" "))
+            } else {
+                format!(
+                    "{}({} / {})",
+                    &cap[1],
+                    components.join(" "),
+                    alpha.join(" ")
+                )
+            }
+        } else {
+            format!("{}({})", &cap[1], values.join(", "))
+        };
+
+        Some(Color::try_parse_css_function(
+            css_function.as_str(),
+            allow_mixed_units,
+        ))
+    }
+
+    fn try_parse_css_function(
+        string: &str,
+        allow_mixed_units: bool,
+    ) -> Result<Color, ParseErrorEnum> {
         lazy_static! {
+            // the values are separated by commas (legacy syntax).
             // cap[1] -> css-function
             // cap[2] -> 1. value
             // cap[3] -> 1. value after dot
-            // cap[4] -> unit of 1. value (° or % or empty)
+            // cap[4] -> unit of 1. value (°, deg, grad, rad, turn, % or empty)
             // cap[5] -> 2., 3. and 4. value
             // cap[6] -> 2. value
             // cap[7] -> 2. value after dot
@@ -3300,98 +5669,139 @@ impl Color {
             // cap[9] -> 3. and 4. value
             // cap[10] -> 3. value
             // cap[11] -> 3. value after dot
-            // cap[12] -> unit of 3. value (% or empty)
+            // cap[12] -> unit of 3. value (°, deg, grad, rad, turn, % or empty)
             // cap[13] -> whole 4. value
             // cap[14] -> 4. value
             // cap[15] -> 4. value after dot
             // cap[16] -> unit of 4. value
-            static ref RE_CSS_FUNCTION: Regex = Regex::new(r"^(cmyk|gray|grey|hsla?|hsva?|hwba?|rgba?)\s*\(\s*(-?\d+(\.\d+)?)\s*(%|°)?\s*(,\s*(-?\d+(\.\d+)?)\s*(%)?\s*(,\s*(-?\d+(\.\d+)?)\s*(%)?\s*(,\s*(-?\d+(\.\d+)?)\s*(%)?\s*)?)?)?\)$").unwrap();
-        }
-        let caps = RE_CSS_FUNCTION.captures(string);
-        if caps.is_none() {
-            return None;
+            static ref RE_CSS_FUNCTION: Regex = Regex::new(r"^(cmyk|gray|grey|hsla?|hsva?|hwba?|lab|oklab|oklch|rgba?)\s*\(\s*(-?\d+(\.\d+)?|none)\s*(%|°|deg|grad|rad|turn)?\s*(,\s*(-?\d+(\.\d+)?|none)\s*(%)?\s*(,\s*(-?\d+(\.\d+)?|none)\s*(%|°|deg|grad|rad|turn)?\s*(,\s*(-?\d+(\.\d+)?|none)\s*(%)?\s*)?)?)?\)$").unwrap();
+            // the values are separated by spaces and the alpha value by a slash (css4 syntax), e.g. "rgb(0 255 0 / 0.5)".
+            // the captures are the same as in RE_CSS_FUNCTION, the separators are part of cap[5] and cap[13].
+            static ref RE_CSS4_FUNCTION: Regex = Regex::new(r"^(cmyk|gray|grey|hsla?|hsva?|hwba?|lab|oklab|oklch|rgba?)\s*\(\s*(-?\d+(\.\d+)?|none)\s*(%|°|deg|grad|rad|turn)?((?:\s*/\s*|\s+)(-?\d+(\.\d+)?|none)\s*(%)?(\s+(-?\d+(\.\d+)?|none)\s*(%|°|deg|grad|rad|turn)?((?:\s*/\s*|\s+)(-?\d+(\.\d+)?|none)\s*(%)?)?)?)?\s*\)$").unwrap();
         }
-
-        let cap = caps.unwrap();
+        let (cap, is_css4_syntax) = match RE_CSS_FUNCTION.captures(string) {
+            Some(cap) => (cap, false),
+            None => match RE_CSS4_FUNCTION.captures(string) {
+                Some(cap) => (cap, true),
+                None => return Err(ParseErrorEnum::InvalidCssFunction),
+            },
+        };
         let css_function = &cap[1];
-        let mut force_alpha = false;
+        // the alpha value is optional for all functions, "rgba(255, 0, 0)" is as opaque as "rgb(255, 0, 0)".
         let css_base_function = match css_function {
             "cmyk" => "cmyk",
             "gray" => "gray",
             "grey" => "gray",
-            "rgb" => "rgb",
-            "rgba" => {
-                force_alpha = true;
-                "rgb"
-            }
-            "hsl" => "hsl",
-            "hsla" => {
-                force_alpha = true;
-                "hsl"
+            "rgb" | "rgba" => "rgb",
+            "hsl" | "hsla" => "hsl",
+            "hsv" | "hsva" => "hsv",
+            "hwb" | "hwba" => "hwb",
+            "lab" => "lab",
+            "oklab" => "oklab",
+            "oklch" => "oklch",
+            _ => "",
+        };
+        if is_css4_syntax {
+            // only the alpha value is separated by a slash, which is the 2. value of gray()
+            // and the 4. value of all other functions, except for cmyk(), that has no alpha value.
+            let is_separated_by_slash =
+                |whole: usize, value: usize| match (cap.get(whole), cap.get(value)) {
+                    (Some(whole), Some(value)) => {
+                        string[whole.start()..value.start()].contains('/')
+                    }
+                    _ => false,
+                };
+            if cap.get(6).is_some() && is_separated_by_slash(5, 6) != (css_base_function == "gray")
+                || cap.get(10).is_some() && css_base_function == "gray"
+                || cap.get(14).is_some()
+                    && is_separated_by_slash(13, 14) == (css_base_function == "cmyk")
+            {
+                return Err(ParseErrorEnum::InvalidCssFunction);
             }
-            "hsv" => "hsv",
-            "hsva" => {
-                force_alpha = true;
-                "hsv"
+        } else if matches!(css_base_function, "lab" | "oklab" | "oklch") {
+            // the newer css functions don't support the legacy syntax with commas.
+            return Err(ParseErrorEnum::InvalidCssFunction);
+        }
+
+        // the keyword "none" (e.g. the hue of achromatic colors) is only supported by oklab() and oklch(),
+        // where it's equivalent to 0 and can't have a unit.
+        for index in [2, 6, 10, 14] {
+            if cap.get(index).is_some_and(|value| value.as_str() == "none")
+                && (!matches!(css_base_function, "oklab" | "oklch") || cap.get(index + 2).is_some())
+            {
+                return Err(ParseErrorEnum::InvalidCssFunction);
             }
-            "hwb" => "hwb",
-            "hwba" => {
-                force_alpha = true;
-                "hwb"
+        }
+        let parse_value = |value: &str| -> f64 {
+            if value == "none" {
+                0.0
+            } else {
+                value.parse().unwrap()
             }
-            _ => "",
         };
 
-        let mut value_1: f64 = String::from(&cap[2]).parse().unwrap();
+        let mut value_1: f64 = parse_value(&cap[2]);
         let value_2_opt = if cap.get(6).is_some() && cap[6].len() > 0 {
-            let float: f64 = String::from(&cap[6]).parse().unwrap();
-            Some(float)
+            Some(parse_value(&cap[6]))
         } else {
             None
         };
         let value_3_opt = if cap.get(10).is_some() && cap[10].len() > 0 {
-            let float: f64 = String::from(&cap[10]).parse().unwrap();
-            Some(float)
+            Some(parse_value(&cap[10]))
         } else {
             None
         };
         let value_4_opt = if cap.get(14).is_some() && cap[14].len() > 0 {
-            let float: f64 = String::from(&cap[14]).parse().unwrap();
-            Some(float)
+            Some(parse_value(&cap[14]))
         } else {
             None
         };
 
-        let get_alpha = |alpha_option: Option<f64>, is_percentage: bool| -> Option<u8> {
-            if alpha_option.is_some() {
-                let mut alpha = alpha_option.unwrap();
-                if alpha < 0.0 {
-                    alpha = 0.0;
-                } else if is_percentage && alpha > 100.0 {
-                    alpha = 100.0;
-                } else if !is_percentage && alpha > 1.0 {
-                    alpha = 1.0;
-                }
-                if is_percentage {
-                    alpha /= 100.0;
-                }
+        let value_4_is_percentage = cap.get(16).is_some() && &cap[16] == "%";
+        let is_angle = |index: usize| {
+            cap.get(index)
+                .is_some_and(|unit| matches!(unit.as_str(), "°" | "deg" | "grad" | "rad" | "turn"))
+        };
+        let to_degrees = |value: f64, index: usize| match cap.get(index).map(|unit| unit.as_str()) {
+            Some("grad") => value * 0.9,
+            Some("rad") => value * Color::RAD2DEG,
+            Some("turn") => value * 360.0,
+            _ => value,
+        };
+        let value_1_is_angle = is_angle(4);
+        let hue = to_degrees(value_1, 4);
+        // only the hue of oklch() is the 3. value.
+        if is_angle(12) && css_base_function != "oklch" {
+            return Err(ParseErrorEnum::UnexpectedAngleUnit);
+        }
 
-                Some((alpha * 255.0).round() as u8)
-            } else {
-                if force_alpha {
-                    return None;
+        let get_alpha = |alpha_option: Option<f64>, is_percentage: bool| -> u8 {
+            match alpha_option {
+                Some(mut alpha) => {
+                    if alpha < 0.0 {
+                        alpha = 0.0;
+                    } else if is_percentage && alpha > 100.0 {
+                        alpha = 100.0;
+                    } else if !is_percentage && alpha > 1.0 {
+                        alpha = 1.0;
+                    }
+                    if is_percentage {
+                        alpha /= 100.0;
+                    }
+
+                    (alpha * 255.0).round() as u8
                 }
-                Some(255)
+                None => 255,
             }
         };
 
         match css_base_function {
             "cmyk" => {
                 if value_2_opt.is_none() || value_3_opt.is_none() || value_4_opt.is_none() {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
-                if &cap[4] == "°" {
-                    return None;
+                if value_1_is_angle {
+                    return Err(ParseErrorEnum::UnexpectedAngleUnit);
                 }
 
                 let value_2 = value_2_opt.unwrap();
@@ -3404,139 +5814,96 @@ impl Color {
                     value_3 / 100.0,
                     value_4 / 100.0,
                 );
-                Some(Color::new_rgb(rgb.0, rgb.1, rgb.2))
+                Ok(Color::new_rgb(rgb.0, rgb.1, rgb.2))
             }
             "rgb" => {
                 if value_2_opt.is_none() || value_3_opt.is_none() {
-                    return None;
-                }
-                if cap.get(4).is_some() && &cap[4] == "°" {
-                    return None;
-                }
-                let mut value_2 = value_2_opt.unwrap();
-                let mut value_3 = value_3_opt.unwrap();
-                let is_in_percentage_mode = if cap.get(4).is_some() && &cap[4] == "%" {
-                    true
-                } else {
-                    false
-                };
-                if is_in_percentage_mode {
-                    if &cap[8] != "%" || &cap[12] != "%" {
-                        return None;
-                    }
-                }
-                if value_1 < 0.0 {
-                    value_1 = 0.0;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
-                if value_2 < 0.0 {
-                    value_2 = 0.0;
+                if value_1_is_angle {
+                    return Err(ParseErrorEnum::UnexpectedAngleUnit);
                 }
-                if value_3 < 0.0 {
-                    value_3 = 0.0;
-                }
-
-                let alpha_opt = get_alpha(value_4_opt, false);
-                if alpha_opt.is_none() {
-                    return None;
+                let value_2 = value_2_opt.unwrap();
+                let value_3 = value_3_opt.unwrap();
+                let value_1_is_percentage = cap.get(4).is_some_and(|unit| unit.as_str() == "%");
+                let value_2_is_percentage = cap.get(8).is_some_and(|unit| unit.as_str() == "%");
+                let value_3_is_percentage = cap.get(12).is_some_and(|unit| unit.as_str() == "%");
+                if !allow_mixed_units
+                    && (value_2_is_percentage != value_1_is_percentage
+                        || value_3_is_percentage != value_1_is_percentage)
+                {
+                    return Err(ParseErrorEnum::MixedUnits);
                 }
 
-                let rgb = if is_in_percentage_mode {
-                    value_1 /= 100.0;
-                    value_2 /= 100.0;
-                    value_3 /= 100.0;
-                    if value_1 > 1.0 {
-                        value_1 = 1.0;
-                    }
-                    if value_2 > 1.0 {
-                        value_2 = 1.0;
-                    }
-                    if value_3 > 1.0 {
-                        value_3 = 1.0;
-                    }
+                let alpha = get_alpha(value_4_opt, value_4_is_percentage);
 
-                    (
-                        (value_1 * 255.0).round() as u8,
-                        (value_2 * 255.0).round() as u8,
-                        (value_3 * 255.0).round() as u8,
-                    )
-                } else {
-                    if value_1 > 255.0 {
-                        value_1 = 255.0;
-                    }
-                    if value_2 > 255.0 {
-                        value_2 = 255.0;
+                let get_channel = |value: f64, is_percentage: bool| -> u8 {
+                    if is_percentage {
+                        (value.clamp(0.0, 100.0) / 100.0 * 255.0).round() as u8
+                    } else {
+                        value.clamp(0.0, 255.0).round() as u8
                     }
-                    if value_3 > 255.0 {
-                        value_3 = 255.0;
-                    }
-
-                    (
-                        value_1.round() as u8,
-                        value_2.round() as u8,
-                        value_3.round() as u8,
-                    )
                 };
+                let rgb = (
+                    get_channel(value_1, value_1_is_percentage),
+                    get_channel(value_2, value_2_is_percentage),
+                    get_channel(value_3, value_3_is_percentage),
+                );
 
-                Some(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha_opt.unwrap()))
+                Ok(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha))
             }
             "hsl" => {
                 if value_2_opt.is_none() || value_3_opt.is_none() {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
                 if cap.get(4).is_some() && &cap[4] == "%" {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
 
                 let value_2 = value_2_opt.unwrap();
                 let value_3 = value_3_opt.unwrap();
-                let alpha_opt = get_alpha(value_4_opt, false);
-                if alpha_opt.is_none() {
-                    return None;
-                }
+                let alpha = get_alpha(value_4_opt, value_4_is_percentage);
 
-                let rgb = Color::get_rgb_from_hsl(value_1, value_2 / 100.0, value_3 / 100.0);
+                let rgb = Color::get_rgb_from_hsl(hue, value_2 / 100.0, value_3 / 100.0);
 
-                Some(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha_opt.unwrap()))
+                Ok(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha))
             }
             "hsv" => {
                 if value_2_opt.is_none() || value_3_opt.is_none() {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
                 if cap.get(4).is_some() && &cap[4] == "%" {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
 
                 let value_2 = value_2_opt.unwrap();
                 let value_3 = value_3_opt.unwrap();
-                let alpha_opt = get_alpha(value_4_opt, false);
-                if alpha_opt.is_none() {
-                    return None;
-                }
+                let alpha = get_alpha(value_4_opt, value_4_is_percentage);
 
-                let rgb = Color::get_rgb_from_hsv(value_1, value_2 / 100.0, value_3 / 100.0);
+                let rgb = Color::get_rgb_from_hsv(hue, value_2 / 100.0, value_3 / 100.0);
 
-                Some(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha_opt.unwrap()))
+                Ok(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha))
             }
             "hwb" => {
                 if value_2_opt.is_none() || value_3_opt.is_none() {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
                 if cap.get(4).is_some() && &cap[4] == "%" {
-                    return None;
+                    return Err(ParseErrorEnum::InvalidCssFunction);
                 }
 
                 let value_2 = value_2_opt.unwrap();
                 let value_3 = value_3_opt.unwrap();
-                let alpha_opt = get_alpha(value_4_opt, false);
-                if alpha_opt.is_none() {
-                    return None;
-                }
+                let alpha = get_alpha(value_4_opt, value_4_is_percentage);
 
-                let rgb = Color::get_rgb_from_hwb(value_1, value_2 / 100.0, value_3 / 100.0);
+                let rgb = Color::get_rgb_from_hwb(hue, value_2 / 100.0, value_3 / 100.0);
 
-                Some(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha_opt.unwrap()))
+                Ok(Color::new_rgba(rgb.0, rgb.1, rgb.2, alpha))
             }
             "gray" => {
+                if value_1_is_angle {
+                    return Err(ParseErrorEnum::UnexpectedAngleUnit);
+                }
                 let is_in_percentage_mode = if cap.get(4).is_some() && &cap[4] == "%" {
                     true
                 } else {
@@ -3561,11 +5928,74 @@ impl Color {
                     value_1.round() as u8
                 };
 
-                let alpha = get_alpha(value_2_opt, alpha_is_in_percentage_mode).unwrap_or(255);
+                let alpha = get_alpha(value_2_opt, alpha_is_in_percentage_mode);
 
-                Some(Color::new_rgba(gray_value, gray_value, gray_value, alpha))
+                Ok(Color::new_rgba(gray_value, gray_value, gray_value, alpha))
             }
-            _ => None,
+            "lab" => {
+                if value_2_opt.is_none() || value_3_opt.is_none() {
+                    return Err(ParseErrorEnum::InvalidCssFunction);
+                }
+                if value_1_is_angle {
+                    return Err(ParseErrorEnum::UnexpectedAngleUnit);
+                }
+                // 100% of the lightness is 100.0 and 100% of a or b is 125.0.
+                let get_axis = |value: f64, unit: Option<regex::Match>| -> f64 {
+                    if unit.is_some_and(|unit| unit.as_str() == "%") {
+                        value / 100.0 * 125.0
+                    } else {
+                        value
+                    }
+                };
+                let a = get_axis(value_2_opt.unwrap(), cap.get(8));
+                let b = get_axis(value_3_opt.unwrap(), cap.get(12));
+                let alpha = get_alpha(value_4_opt, value_4_is_percentage);
+
+                Ok(Color::new_laba(
+                    value_1.clamp(0.0, 100.0),
+                    a,
+                    b,
+                    alpha as f64 / 255.0,
+                ))
+            }
+            "oklab" | "oklch" => {
+                if value_2_opt.is_none() || value_3_opt.is_none() {
+                    return Err(ParseErrorEnum::InvalidCssFunction);
+                }
+                if value_1_is_angle {
+                    return Err(ParseErrorEnum::UnexpectedAngleUnit);
+                }
+                // 100% of the lightness is 1.0 and 100% of a, b or the chroma is 0.4.
+                let lightness = if cap.get(4).is_some_and(|unit| unit.as_str() == "%") {
+                    value_1 / 100.0
+                } else {
+                    value_1
+                };
+                let get_axis = |value: f64, unit: Option<regex::Match>| -> f64 {
+                    if unit.is_some_and(|unit| unit.as_str() == "%") {
+                        value / 100.0 * 0.4
+                    } else {
+                        value
+                    }
+                };
+                let value_2 = get_axis(value_2_opt.unwrap(), cap.get(8));
+                let alpha = get_alpha(value_4_opt, value_4_is_percentage);
+
+                let (a, b) = if css_base_function == "oklab" {
+                    (value_2, get_axis(value_3_opt.unwrap(), cap.get(12)))
+                } else {
+                    if cap.get(12).is_some_and(|unit| unit.as_str() == "%") {
+                        return Err(ParseErrorEnum::InvalidCssFunction);
+                    }
+                    // a negative chroma is clamped to 0, like in css.
+                    let chroma = value_2.max(0.0);
+                    let hue = to_degrees(value_3_opt.unwrap(), 12) * Color::DEG2RAD;
+                    (chroma * hue.cos(), chroma * hue.sin())
+                };
+
+                Ok(Color::from_oklab(lightness.clamp(0.0, 1.0), a, b, alpha))
+            }
+            _ => Err(ParseErrorEnum::InvalidCssFunction),
         }
     }
 
@@ -3672,155 +6102,33 @@ impl Color {
     }
 
     fn try_parse_known_color(string: &str) -> Option<Color> {
+        Color::KNOWN_COLORS
+            .iter()
+            .find(|(name, _)| *name == string)
+            .map(|(_, known_color)| Color::new_enum(*known_color))
+    }
+
+    fn try_parse_system_color(string: &str) -> Option<Color> {
         match string {
-            "aliceblue" => Some(Color::new_enum(KnownColors::AliceBlue)),
-            "antiquewhite" => Some(Color::new_enum(KnownColors::AntiqueWhite)),
-            "aqua" => Some(Color::new_enum(KnownColors::Aqua)),
-            "aquamarine" => Some(Color::new_enum(KnownColors::AquaMarine)),
-            "azure" => Some(Color::new_enum(KnownColors::Azure)),
-            "beige" => Some(Color::new_enum(KnownColors::Beige)),
-            "bisque" => Some(Color::new_enum(KnownColors::Bisque)),
-            "black" => Some(Color::new_enum(KnownColors::Black)),
-            "blanchedalmond" => Some(Color::new_enum(KnownColors::BlanchedAlmond)),
-            "blue" => Some(Color::new_enum(KnownColors::Blue)),
-            "blueviolet" => Some(Color::new_enum(KnownColors::BlueViolet)),
-            "brown" => Some(Color::new_enum(KnownColors::Brown)),
-            "burlywood" => Some(Color::new_enum(KnownColors::BurlyWood)),
-            "cadetblue" => Some(Color::new_enum(KnownColors::CadetBlue)),
-            "chartreuse" => Some(Color::new_enum(KnownColors::Chartreuse)),
-            "chocolate" => Some(Color::new_enum(KnownColors::Chocolate)),
-            "coral" => Some(Color::new_enum(KnownColors::Coral)),
-            "cornflowerblue" => Some(Color::new_enum(KnownColors::CornflowerBlue)),
-            "cornsilk" => Some(Color::new_enum(KnownColors::Cornsilk)),
-            "crimson" => Some(Color::new_enum(KnownColors::Crimson)),
-            "cyan" => Some(Color::new_enum(KnownColors::Cyan)),
-            "darkblue" => Some(Color::new_enum(KnownColors::DarkBlue)),
-            "darkcyan" => Some(Color::new_enum(KnownColors::DarkCyan)),
-            "darkgoldenrod" => Some(Color::new_enum(KnownColors::DarkGoldenrod)),
-            "darkgray" => Some(Color::new_enum(KnownColors::DarkGray)),
-            "darkgrey" => Some(Color::new_enum(KnownColors::DarkGray)),
-            "darkgreen" => Some(Color::new_enum(KnownColors::DarkGreen)),
-            "darkkhaki" => Some(Color::new_enum(KnownColors::DarkKhaki)),
-            "darkmagenta" => Some(Color::new_enum(KnownColors::DarkMagenta)),
-            "darkolivegreen" => Some(Color::new_enum(KnownColors::DarkOliveGreen)),
-            "darkorange" => Some(Color::new_enum(KnownColors::DarkOrange)),
-            "darkorchid" => Some(Color::new_enum(KnownColors::DarkOrchid)),
-            "darkred" => Some(Color::new_enum(KnownColors::DarkRed)),
-            "darksalmon" => Some(Color::new_enum(KnownColors::DarkSalmon)),
-            "darkseagreen" => Some(Color::new_enum(KnownColors::DarkSeaGreen)),
-            "darkslateblue" => Some(Color::new_enum(KnownColors::DarkSlateBlue)),
-            "darkslategray" => Some(Color::new_enum(KnownColors::DarkSlateGray)),
-            "darkslategrey" => Some(Color::new_enum(KnownColors::DarkSlateGray)),
-            "darkturquoise" => Some(Color::new_enum(KnownColors::DarkTurquoise)),
-            "darkviolet" => Some(Color::new_enum(KnownColors::DarkViolet)),
-            "deeppink" => Some(Color::new_enum(KnownColors::DeepPink)),
-            "deepskyblue" => Some(Color::new_enum(KnownColors::DeepSkyBlue)),
-            "dimgray" => Some(Color::new_enum(KnownColors::DimGray)),
-            "dimgrey" => Some(Color::new_enum(KnownColors::DimGray)),
-            "dodgerblue" => Some(Color::new_enum(KnownColors::DodgerBlue)),
-            "firebrick" => Some(Color::new_enum(KnownColors::Firebrick)),
-            "floralwhite" => Some(Color::new_enum(KnownColors::FloralWhite)),
-            "forestgreen" => Some(Color::new_enum(KnownColors::ForestGreen)),
-            "fuchsia" => Some(Color::new_enum(KnownColors::Fuchsia)),
-            "gainsboro" => Some(Color::new_enum(KnownColors::Gainsboro)),
-            "ghostwhite" => Some(Color::new_enum(KnownColors::GhostWhite)),
-            "gold" => Some(Color::new_enum(KnownColors::Gold)),
-            "goldenrod" => Some(Color::new_enum(KnownColors::Goldenrod)),
-            "gray" => Some(Color::new_enum(KnownColors::Gray)),
-            "grey" => Some(Color::new_enum(KnownColors::Gray)),
-            "green" => Some(Color::new_enum(KnownColors::Green)),
-            "greenyellow" => Some(Color::new_enum(KnownColors::GreenYellow)),
-            "honeydew" => Some(Color::new_enum(KnownColors::Honeydew)),
-            "hotpink" => Some(Color::new_enum(KnownColors::HotPink)),
-            "indianred" => Some(Color::new_enum(KnownColors::IndianRed)),
-            "indigo" => Some(Color::new_enum(KnownColors::Indigo)),
-            "ivory" => Some(Color::new_enum(KnownColors::Ivory)),
-            "khaki" => Some(Color::new_enum(KnownColors::Khaki)),
-            "lavender" => Some(Color::new_enum(KnownColors::Lavender)),
-            "lavenderblush" => Some(Color::new_enum(KnownColors::LavenderBlush)),
-            "lawngreen" => Some(Color::new_enum(KnownColors::LawnGreen)),
-            "lemonchiffon" => Some(Color::new_enum(KnownColors::LemonChiffon)),
-            "lightblue" => Some(Color::new_enum(KnownColors::LightBlue)),
-            "lightcoral" => Some(Color::new_enum(KnownColors::LightCoral)),
-            "lightcyan" => Some(Color::new_enum(KnownColors::LightCyan)),
-            "lightgoldenrodyellow" => Some(Color::new_enum(KnownColors::LightGoldenrodYellow)),
-            "lightgray" => Some(Color::new_enum(KnownColors::LightGray)),
-            "lightgrey" => Some(Color::new_enum(KnownColors::LightGray)),
-            "lightgreen" => Some(Color::new_enum(KnownColors::LightGreen)),
-            "lightpink" => Some(Color::new_enum(KnownColors::LightPink)),
-            "lightsalmon" => Some(Color::new_enum(KnownColors::LightSalmon)),
-            "lightseagreen" => Some(Color::new_enum(KnownColors::LightSeaGreen)),
-            "lightskyblue" => Some(Color::new_enum(KnownColors::LightSkyBlue)),
-            "lightslategray" => Some(Color::new_enum(KnownColors::LightSlateGray)),
-            "lightslategrey" => Some(Color::new_enum(KnownColors::LightSlateGray)),
-            "lightsteelblue" => Some(Color::new_enum(KnownColors::LightSteelBlue)),
-            "lightyellow" => Some(Color::new_enum(KnownColors::LightYellow)),
-            "lime" => Some(Color::new_enum(KnownColors::Lime)),
-            "limegreen" => Some(Color::new_enum(KnownColors::LimeGreen)),
-            "linen" => Some(Color::new_enum(KnownColors::Linen)),
-            "magenta" => Some(Color::new_enum(KnownColors::Magenta)),
-            "maroon" => Some(Color::new_enum(KnownColors::Maroon)),
-            "mediumaquamarine" => Some(Color::new_enum(KnownColors::MediumAquaMarine)),
-            "mediumblue" => Some(Color::new_enum(KnownColors::MediumBlue)),
-            "mediumorchid" => Some(Color::new_enum(KnownColors::MediumOrchid)),
-            "mediumpurple" => Some(Color::new_enum(KnownColors::MediumPurple)),
-            "mediumseagreen" => Some(Color::new_enum(KnownColors::MediumSeaGreen)),
-            "mediumslateblue" => Some(Color::new_enum(KnownColors::MediumSlateBlue)),
-            "mediumspringgreen" => Some(Color::new_enum(KnownColors::MediumSpringGreen)),
-            "mediumturquoise" => Some(Color::new_enum(KnownColors::MediumTurquoise)),
-            "mediumvioletred" => Some(Color::new_enum(KnownColors::MediumVioletRed)),
-            "midnightblue" => Some(Color::new_enum(KnownColors::MidnightBlue)),
-            "mintcream" => Some(Color::new_enum(KnownColors::MintCream)),
-            "mistyrose" => Some(Color::new_enum(KnownColors::MistyRose)),
-            "moccasin" => Some(Color::new_enum(KnownColors::Moccasin)),
-            "navajowhite" => Some(Color::new_enum(KnownColors::NavajoWhite)),
-            "navy" => Some(Color::new_enum(KnownColors::Navy)),
-            "oldlace" => Some(Color::new_enum(KnownColors::OldLace)),
-            "olive" => Some(Color::new_enum(KnownColors::Olive)),
-            "olivedrab" => Some(Color::new_enum(KnownColors::OliveDrab)),
-            "orange" => Some(Color::new_enum(KnownColors::Orange)),
-            "orangered" => Some(Color::new_enum(KnownColors::OrangeRed)),
-            "orchid" => Some(Color::new_enum(KnownColors::Orchid)),
-            "palegoldenrod" => Some(Color::new_enum(KnownColors::PaleGoldenrod)),
-            "palegreen" => Some(Color::new_enum(KnownColors::PaleGreen)),
-            "paleturquoise" => Some(Color::new_enum(KnownColors::PaleTurquoise)),
-            "palevioletred" => Some(Color::new_enum(KnownColors::PaleVioletRed)),
-            "papayawhip" => Some(Color::new_enum(KnownColors::PapayaWhip)),
-            "peachpuff" => Some(Color::new_enum(KnownColors::PeachPuff)),
-            "peru" => Some(Color::new_enum(KnownColors::Peru)),
-            "pink" => Some(Color::new_enum(KnownColors::Pink)),
-            "plum" => Some(Color::new_enum(KnownColors::Plum)),
-            "powderblue" => Some(Color::new_enum(KnownColors::PowderBlue)),
-            "purple" => Some(Color::new_enum(KnownColors::Purple)),
-            "red" => Some(Color::new_enum(KnownColors::Red)),
-            "rosybrown" => Some(Color::new_enum(KnownColors::RosyBrown)),
-            "royalblue" => Some(Color::new_enum(KnownColors::RoyalBlue)),
-            "saddlebrown" => Some(Color::new_enum(KnownColors::SaddleBrown)),
-            "salmon" => Some(Color::new_enum(KnownColors::Salmon)),
-            "sandybrown" => Some(Color::new_enum(KnownColors::SandyBrown)),
-            "seagreen" => Some(Color::new_enum(KnownColors::SeaGreen)),
-            "seashell" => Some(Color::new_enum(KnownColors::SeaShell)),
-            "sienna" => Some(Color::new_enum(KnownColors::Sienna)),
-            "silver" => Some(Color::new_enum(KnownColors::Silver)),
-            "skyblue" => Some(Color::new_enum(KnownColors::SkyBlue)),
-            "slateblue" => Some(Color::new_enum(KnownColors::SlateBlue)),
-            "slategray" => Some(Color::new_enum(KnownColors::SlateGray)),
-            "slategrey" => Some(Color::new_enum(KnownColors::SlateGray)),
-            "snow" => Some(Color::new_enum(KnownColors::Snow)),
-            "springgreen" => Some(Color::new_enum(KnownColors::SpringGreen)),
-            "steelblue" => Some(Color::new_enum(KnownColors::SteelBlue)),
-            "tan" => Some(Color::new_enum(KnownColors::Tan)),
-            "teal" => Some(Color::new_enum(KnownColors::Teal)),
-            "thistle" => Some(Color::new_enum(KnownColors::Thistle)),
-            "tomato" => Some(Color::new_enum(KnownColors::Tomato)),
-            "transparent" => Some(Color::new_enum(KnownColors::Transparent)),
-            "turquoise" => Some(Color::new_enum(KnownColors::Turquoise)),
-            "violet" => Some(Color::new_enum(KnownColors::Violet)),
-            "wheat" => Some(Color::new_enum(KnownColors::Wheat)),
-            "white" => Some(Color::new_enum(KnownColors::White)),
-            "whitesmoke" => Some(Color::new_enum(KnownColors::WhiteSmoke)),
-            "yellow" => Some(Color::new_enum(KnownColors::Yellow)),
-            "yellowgreen" => Some(Color::new_enum(KnownColors::YellowGreen)),
+            "accentcolor" => Some(Color::new_rgb(0x00, 0x78, 0xD7)),
+            "accentcolortext" => Some(Color::new_rgb(0xFF, 0xFF, 0xFF)),
+            "activetext" => Some(Color::new_rgb(0xFF, 0x00, 0x00)),
+            "buttonborder" => Some(Color::new_rgb(0x76, 0x76, 0x76)),
+            "buttonface" => Some(Color::new_rgb(0xEF, 0xEF, 0xEF)),
+            "buttontext" => Some(Color::new_rgb(0x00, 0x00, 0x00)),
+            "canvas" => Some(Color::new_rgb(0xFF, 0xFF, 0xFF)),
+            "canvastext" => Some(Color::new_rgb(0x00, 0x00, 0x00)),
+            "field" => Some(Color::new_rgb(0xFF, 0xFF, 0xFF)),
+            "fieldtext" => Some(Color::new_rgb(0x00, 0x00, 0x00)),
+            "graytext" => Some(Color::new_rgb(0x80, 0x80, 0x80)),
+            "highlight" => Some(Color::new_rgb(0xB5, 0xD5, 0xFF)),
+            "highlighttext" => Some(Color::new_rgb(0x00, 0x00, 0x00)),
+            "linktext" => Some(Color::new_rgb(0x00, 0x00, 0xEE)),
+            "mark" => Some(Color::new_rgb(0xFF, 0xFF, 0x00)),
+            "marktext" => Some(Color::new_rgb(0x00, 0x00, 0x00)),
+            "selecteditem" => Some(Color::new_rgb(0x00, 0x78, 0xD7)),
+            "selecteditemtext" => Some(Color::new_rgb(0xFF, 0xFF, 0xFF)),
+            "visitedtext" => Some(Color::new_rgb(0x55, 0x1A, 0x8B)),
             _ => None,
         }
     }
@@ -3950,6 +6258,9 @@ impl FromStr for Color {
     /// assert_eq!(128, green.green);
     /// assert_eq!(0, green.blue);
     /// assert_eq!(255, green.alpha);
+    ///
+    /// // the error is the reason of the ParseError as String, e.g. for empty or whitespace-only strings:
+    /// assert_eq!(Err(String::from("EmptyString")), "   ".parse::<Color>());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match Color::new_string(s) {
@@ -3959,6 +6270,159 @@ impl FromStr for Color {
     }
 }
 
+impl IntoIterator for &Color {
+    type Item = u8;
+    type IntoIter = std::array::IntoIter<u8, 4>;
+
+    /// Iterates over the red, green, blue and alpha values of the color.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::Color;
+    ///
+    /// let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+    /// let mut sum = 0;
+    /// for channel in &transparent_green {
+    ///     sum += channel as u16;
+    /// }
+    ///
+    /// assert_eq!(383, sum);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.channels().into_iter()
+    }
+}
+
+/// The color spaces, in which colors can be mixed with [Color::mix](struct.Color.html#method.mix).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MixSpace {
+    Rgb,
+    Hsl,
+    Hsv,
+    Hwb,
+    Lab,
+    Lch,
+}
+
+/// The luma standards, which define the weights of the red, green and blue values
+/// for [Color::grayscale_weighted](struct.Color.html#method.grayscale_weighted).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LumaStandard {
+    /// ITU-R BT.601, as used by PAL and NTSC systems (`0.299, 0.587, 0.114`).
+    Bt601,
+    /// ITU-R BT.709, as used by HDTV systems (`0.2126, 0.7152, 0.0722`).
+    Bt709,
+    /// ITU-R BT.2100, as used by UHDTV and HDR systems (`0.2627, 0.678, 0.0593`).
+    Bt2100,
+}
+
+impl LumaStandard {
+    /// Gets the weights of the red, green and blue values, which add up to 1.0.
+    ///
+    /// # Example
+    /// ```
+    /// use color_processing::LumaStandard;
+    ///
+    /// assert_eq!((0.2126, 0.7152, 0.0722), LumaStandard::Bt709.weights());
+    /// ```
+    pub fn weights(&self) -> (f64, f64, f64) {
+        match self {
+            LumaStandard::Bt601 => (0.299, 0.587, 0.114),
+            LumaStandard::Bt709 => (0.2126, 0.7152, 0.0722),
+            LumaStandard::Bt2100 => (0.2627, 0.678, 0.0593),
+        }
+    }
+}
+
+/// The alpha modes, in which colors can be mixed with [Color::mix_with_alpha_mode](struct.Color.html#method.mix_with_alpha_mode).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlphaMode {
+    Straight,
+    Premultiplied,
+}
+
+/// The color vision deficiencies, that can be simulated with [Color::simulate_color_blindness](struct.Color.html#method.simulate_color_blindness)
+/// and corrected with [Color::daltonize](struct.Color.html#method.daltonize).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorBlindness {
+    /// The absence of the red (long wavelength) cones.
+    Protanopia,
+    /// The absence of the green (medium wavelength) cones.
+    Deuteranopia,
+    /// The absence of the blue (short wavelength) cones.
+    Tritanopia,
+}
+
+/// The css syntax styles, in which colors can be formatted, e.g. with [Color::to_hsl_string_styled](struct.Color.html#method.to_hsl_string_styled).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FormatStyle {
+    /// Comma separated values, e.g. `hsla(120, 100%, 50%, 0.5)`.
+    Legacy,
+    /// Space separated values with a slash before the alpha value, e.g. `hsl(120 100% 50% / 0.5)`.
+    Css4,
+}
+
+/// The sectors of the hue wheel, as returned by [Color::hue_sector](struct.Color.html#method.hue_sector).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HueSector {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Magenta,
+}
+
+/// The byte orders of the channels in a packed u32 number, as used by [Color::to_packed_u32](struct.Color.html#method.to_packed_u32)
+/// and [Color::from_packed_u32](struct.Color.html#method.from_packed_u32). The channels are named from the most to the least significant byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    /// `0xAARRGGBB`, like [Color::to_number](struct.Color.html#method.to_number).
+    Argb,
+    /// `0xRRGGBBAA`, like the css hex notation `#RRGGBBAA`.
+    Rgba,
+    /// `0xAABBGGRR`, the byte order of RGBA8888 pixels in little-endian memory.
+    Abgr,
+    /// `0xBBGGRRAA`.
+    Bgra,
+}
+
+/// The differences between two colors, as returned by [Color::diff](struct.Color.html#method.diff).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorDiff {
+    pub red: i16,
+    pub green: i16,
+    pub blue: i16,
+    pub alpha: i16,
+    /// The [CIEDE2000](https://en.wikipedia.org/wiki/Color_difference#CIEDE2000) color difference.
+    pub delta_e: f64,
+    /// The hue difference in degrees (from -180.0 to 180.0).
+    pub hue: f64,
+}
+
+/// The CIE Lab values of a color, as returned by [Color::get_lab](struct.Color.html#method.get_lab).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lab {
+    /// The lightness (from 0.0 to 100.0).
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// The CIE Lch values of a color, as returned by [Color::get_lch](struct.Color.html#method.get_lch).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lch {
+    /// The lightness (from 0.0 to 100.0).
+    pub l: f64,
+    /// The chroma.
+    pub c: f64,
+    /// The hue in degrees (from 0.0 to 360.0), NaN for achromatic colors.
+    pub h: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum KnownColors {
     AliceBlue,
     AntiqueWhite,
@@ -1,6 +1,10 @@
 extern crate color_processing;
 
-use color_processing::{Color, KnownColors, ParseError, ParseErrorEnum};
+use color_processing::{
+    AlphaMode, ChannelOrder, Color, ColorBlindness, FormatStyle, HueSector, KnownColors, Lab, Lch,
+    LumaStandard, MixSpace, ParseError, ParseErrorEnum, ParseOptions,
+};
+use std::collections::HashMap;
 
 #[test]
 fn color_new() {
@@ -539,6 +543,38 @@ fn color_new_string_known_color() {
     assert_eq!(white.alpha, 255);
 }
 
+#[test]
+fn color_new_string_system_color() {
+    let canvas_text = Color::new_string("canvastext").unwrap();
+    assert!(canvas_text.get_luminance() < 0.01);
+    assert_eq!(255, canvas_text.alpha);
+
+    let canvas = Color::new_string("Canvas").unwrap();
+    assert_eq!("#FFFFFF", canvas.to_hex_string());
+    assert_eq!("Canvas", canvas.get_original_string());
+
+    let button_face = Color::new_string("ButtonFace").unwrap();
+    let button_text = Color::new_string("ButtonText").unwrap();
+    assert!(button_face.get_contrast(button_text) > 4.5);
+
+    let highlight = Color::new_string("Highlight").unwrap();
+    let highlight_text = Color::new_string("HighlightText").unwrap();
+    assert!(highlight.get_contrast(highlight_text) > 4.5);
+
+    assert_eq!(
+        Color::new_string("canvasbackground"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidColorName
+        })
+    );
+    assert_eq!(
+        Color::new_string("buttontextcolor"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidColorName
+        })
+    );
+}
+
 #[test]
 fn color_new_string_abbr_color() {
     let red = Color::new_string("RD").unwrap();
@@ -649,6 +685,30 @@ fn color_new_string_rgb() {
     assert_eq!(yellow_color.alpha, 128);
 }
 
+#[test]
+fn color_new_string_rgb_fractional() {
+    // fractional values in number mode are rounded, not truncated.
+    let color = Color::new_string("rgb(127.5, 0, 0)").unwrap();
+    assert_eq!(color.red, 128);
+    assert_eq!(color.green, 0);
+    assert_eq!(color.blue, 0);
+
+    let color = Color::new_string("rgb(127.4, 63.5, 0.5)").unwrap();
+    assert_eq!(color.red, 127);
+    assert_eq!(color.green, 64);
+    assert_eq!(color.blue, 1);
+
+    // values above 255 or below 0 are clamped.
+    let color = Color::new_string("rgb(255.9, -0.5, 0)").unwrap();
+    assert_eq!(color.red, 255);
+    assert_eq!(color.green, 0);
+    assert_eq!(color.blue, 0);
+
+    let color = Color::new_string("rgba(254.5, 0, 0, 0.5)").unwrap();
+    assert_eq!(color.red, 255);
+    assert_eq!(color.alpha, 128);
+}
+
 #[test]
 fn color_new_string_rgba() {
     let red_color = Color::new_string("rgba(255, 0, 0, 0.5)").unwrap();
@@ -664,6 +724,263 @@ fn color_new_string_rgba() {
     assert_eq!(green_color.alpha, 128);
 }
 
+#[test]
+fn color_new_string_rgb_with_alpha_units() {
+    // percent rgb values with a percent or fractional alpha.
+    for rgb_string in ["rgb(100%, 0%, 0%, 50%)", "rgb(100%, 0%, 0%, 0.5)"] {
+        let color = Color::new_string(rgb_string).unwrap();
+        assert_eq!(color.red, 255, "{}", rgb_string);
+        assert_eq!(color.green, 0, "{}", rgb_string);
+        assert_eq!(color.blue, 0, "{}", rgb_string);
+        assert_eq!(color.alpha, 128, "{}", rgb_string);
+    }
+
+    // number rgb values with a fractional or percent alpha.
+    for rgb_string in ["rgb(255, 0, 0, 0.5)", "rgb(255, 0, 0, 50%)"] {
+        let color = Color::new_string(rgb_string).unwrap();
+        assert_eq!(color.red, 255, "{}", rgb_string);
+        assert_eq!(color.green, 0, "{}", rgb_string);
+        assert_eq!(color.blue, 0, "{}", rgb_string);
+        assert_eq!(color.alpha, 128, "{}", rgb_string);
+    }
+
+    // percent rgb values must not be mixed with numbers.
+    assert_eq!(
+        Color::new_string("rgb(100%, 0, 0)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::MixedUnits
+        })
+    );
+    assert_eq!(
+        Color::new_string("rgb(100%, 0%, 0, 50%)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::MixedUnits
+        })
+    );
+}
+
+#[test]
+fn color_new_string_alpha_forms() {
+    for opaque in [
+        "rgba(0, 0, 0, 1)",
+        "rgba(0, 0, 0, 1.0)",
+        "rgba(0, 0, 0, 100%)",
+        "hsla(0, 0%, 0%, 1)",
+        "hsla(0, 0%, 0%, 100%)",
+        "hsva(0, 0%, 0%, 1.0)",
+        "hsva(0, 0%, 0%, 100%)",
+        "hwba(0, 0%, 100%, 1)",
+        "hwba(0, 0%, 100%, 100%)",
+        "gray(0, 1)",
+        "gray(0, 100%)",
+    ] {
+        assert_eq!(255, Color::new_string(opaque).unwrap().alpha, "{}", opaque);
+    }
+
+    for transparent in [
+        "rgba(0, 0, 0, 0)",
+        "rgba(0, 0, 0, 0.0)",
+        "rgba(0, 0, 0, 0%)",
+        "hsla(0, 0%, 0%, 0)",
+        "hsla(0, 0%, 0%, 0%)",
+        "hsva(0, 0%, 0%, 0.0)",
+        "hwba(0, 0%, 100%, 0%)",
+        "gray(0, 0)",
+        "gray(0, 0%)",
+    ] {
+        assert_eq!(
+            0,
+            Color::new_string(transparent).unwrap().alpha,
+            "{}",
+            transparent
+        );
+    }
+
+    assert_eq!(128, Color::new_string("rgba(0, 0, 0, 50%)").unwrap().alpha);
+    assert_eq!(
+        128,
+        Color::new_string("hsla(0, 0%, 0%, 50%)").unwrap().alpha
+    );
+    assert_eq!(
+        128,
+        Color::new_string("hsva(0, 0%, 0%, 50%)").unwrap().alpha
+    );
+    assert_eq!(
+        128,
+        Color::new_string("hwba(0, 0%, 0%, 50%)").unwrap().alpha
+    );
+}
+
+#[test]
+fn color_new_string_hex_alpha_round_trip() {
+    let hex_color = Color::new_string("#FF000080").unwrap();
+    let rgb_color = Color::new_string("rgb(255 0 0 / 50.2%)").unwrap();
+    assert_eq!(128, hex_color.alpha);
+    assert_eq!(hex_color.channels(), rgb_color.channels());
+
+    for alpha in 0..=255u8 {
+        let color = Color::new_rgba(255, 0, 0, alpha);
+        let hex_string = color.to_hex_string();
+        assert_eq!(
+            alpha,
+            Color::new_string(&hex_string).unwrap().alpha,
+            "{}",
+            hex_string
+        );
+
+        let rgb_string = format!("rgb(255 0 0 / {}%)", alpha as f64 / 255.0 * 100.0);
+        assert_eq!(
+            alpha,
+            Color::new_string(&rgb_string).unwrap().alpha,
+            "{}",
+            rgb_string
+        );
+    }
+}
+
+#[test]
+fn color_new_string_lab() {
+    let red = Color::new_string("lab(53.24 80.09 67.2)").unwrap();
+    assert_eq!([255, 0, 0, 255], red.channels());
+    let transparent_red = Color::new_string("lab(53.24 80.09 67.2 / 50%)").unwrap();
+    assert_eq!([255, 0, 0, 128], transparent_red.channels());
+
+    // 100% of the a and b axes are 125.
+    assert_eq!(
+        Color::new_lab(50.0, 125.0, -125.0).channels(),
+        Color::new_string("lab(50% 100% -100%)").unwrap().channels()
+    );
+    assert_eq!(
+        Color::new_string("lab(50 125 -125)").unwrap().channels(),
+        Color::new_string("lab(50% 100% -100%)").unwrap().channels()
+    );
+    assert_eq!(
+        Color::new_string("lab(53.24 80.09 67.2)")
+            .unwrap()
+            .channels(),
+        Color::new_string("lab(53.24% 64.072% 53.76%)")
+            .unwrap()
+            .channels()
+    );
+
+    assert_eq!(
+        Color::new_string("lab(50 20)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidCssFunction
+        })
+    );
+    assert_eq!(
+        Color::new_string("lab(50deg 20 20)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::UnexpectedAngleUnit
+        })
+    );
+}
+
+#[test]
+fn color_new_string_oklab_and_oklch() {
+    for red_string in [
+        "oklab(0.628 0.2249 0.1258)",
+        "oklab(62.8% 56.225% 31.45%)",
+        "oklch(0.628 0.2577 29.23)",
+        "OKLCH(0.628 0.2577 29.23 / 1)",
+    ] {
+        let red = Color::new_string(red_string).unwrap();
+        assert_eq!([255, 0, 0, 255], red.channels(), "{}", red_string);
+    }
+
+    // 100% of the a, b and chroma values are 0.4.
+    assert_eq!(
+        Color::new_string("oklab(0.5 0.2 -0.2)").unwrap().channels(),
+        Color::new_string("oklab(0.5 50% -50%)").unwrap().channels()
+    );
+    assert_eq!(
+        Color::new_string("oklch(0.5 0.2 120)").unwrap().channels(),
+        Color::new_string("oklch(50% 50% 120)").unwrap().channels()
+    );
+
+    // a negative chroma is clamped to 0.
+    assert_eq!(
+        Color::new_string("oklch(0.5 0 120)").unwrap().channels(),
+        Color::new_string("oklch(0.5 -0.1 120)").unwrap().channels()
+    );
+
+    let transparent_gray = Color::new_string("oklch(0.5999 0 none / 50%)").unwrap();
+    assert_eq!([128, 128, 128, 128], transparent_gray.channels());
+    let gray = Color::new_string("oklab(0.5999 none none)").unwrap();
+    assert_eq!([128, 128, 128, 255], gray.channels());
+
+    // the hue accepts the same angle units as hsl().
+    for hue_string in ["29.23deg", "32.4778grad", "0.51015rad", "0.081194turn"] {
+        let red = Color::new_string(format!("oklch(0.628 0.2577 {})", hue_string)).unwrap();
+        assert_eq!([255, 0, 0, 255], red.channels(), "{}", hue_string);
+    }
+    assert_eq!(
+        Color::new_string("oklab(0.628 0.2249 0.1258deg)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::UnexpectedAngleUnit
+        })
+    );
+    assert_eq!(
+        Color::new_string("rgb(0, 0, 10deg)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::UnexpectedAngleUnit
+        })
+    );
+
+    // the legacy syntax with commas isn't supported by lab(), oklab() and oklch().
+    for string in [
+        "lab(53.24, 80.09, 67.2, 50%)",
+        "oklab(0.5, 0.1, 0.1)",
+        "oklch(62.8%, 0.2577, 29.23)",
+    ] {
+        assert_eq!(
+            Color::new_string(string),
+            Err(ParseError {
+                reason: ParseErrorEnum::InvalidCssFunction
+            }),
+            "{}",
+            string
+        );
+    }
+
+    // "none" is a keyword and not a part of a value.
+    for string in [
+        "oklab(nonenone 0 0)",
+        "oklab(0.5 none0 0)",
+        "oklch(0.5 0 none%)",
+        "rgb(none 0 0)",
+    ] {
+        assert_eq!(
+            Color::new_string(string),
+            Err(ParseError {
+                reason: ParseErrorEnum::InvalidCssFunction
+            }),
+            "{}",
+            string
+        );
+    }
+
+    assert_eq!(
+        Color::new_string("oklch(0.628 0.2577 29.23%)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidCssFunction
+        })
+    );
+    assert_eq!(
+        Color::new_string("oklab(0.628 0.2249)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidCssFunction
+        })
+    );
+    assert_eq!(
+        Color::new_string("oklab(1turn 0 0)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::UnexpectedAngleUnit
+        })
+    );
+}
+
 #[test]
 fn color_new_string_gray() {
     let light_gray = Color::new_string("gray(100)").unwrap();
@@ -689,6 +1006,23 @@ fn color_new_string_gray() {
     assert_eq!(128, medium_gray.green);
     assert_eq!(128, medium_gray.blue);
     assert_eq!(255, medium_gray.alpha);
+
+    // percent or number gray values with a percent or fractional alpha.
+    for gray_string in [
+        "gray(50%, 50%)",
+        "gray(50%, 0.5)",
+        "gray(128, 50%)",
+        "gray(128, 0.5)",
+        "grey(50%, 50%)",
+    ] {
+        let half_transparent_gray = Color::new_string(gray_string).unwrap();
+        assert_eq!(
+            [128, 128, 128, 128],
+            half_transparent_gray.channels(),
+            "{}",
+            gray_string
+        );
+    }
 }
 
 #[test]
@@ -808,6 +1142,52 @@ fn color_new_string_hsla() {
     assert_eq!(green_color.alpha, 128);
 }
 
+#[test]
+fn color_new_string_hsl_with_alpha() {
+    let hsl_color = Color::new_string("hsl(120, 100%, 50%, 0.5)").unwrap();
+    let hsla_color = Color::new_string("hsla(120, 100%, 50%, 0.5)").unwrap();
+    assert_eq!(hsl_color.red, 0);
+    assert_eq!(hsl_color.green, 255);
+    assert_eq!(hsl_color.blue, 0);
+    assert_eq!(hsl_color.alpha, 128);
+    assert_eq!(hsl_color.get_rgba(), hsla_color.get_rgba());
+
+    let hsv_color = Color::new_string("hsv(120, 100%, 100%, 0.5)").unwrap();
+    let hsva_color = Color::new_string("hsva(120, 100%, 100%, 0.5)").unwrap();
+    assert_eq!(hsv_color.alpha, 128);
+    assert_eq!(hsv_color.get_rgba(), hsva_color.get_rgba());
+
+    let hwb_color = Color::new_string("hwb(120, 0%, 0%, 0.5)").unwrap();
+    let hwba_color = Color::new_string("hwba(120, 0%, 0%, 0.5)").unwrap();
+    assert_eq!(hwb_color.alpha, 128);
+    assert_eq!(hwb_color.get_rgba(), hwba_color.get_rgba());
+}
+
+#[test]
+fn color_new_string_alpha_functions_without_alpha() {
+    let pairs = [
+        ("rgba(255, 0, 0)", "rgb(255, 0, 0)"),
+        ("rgba(100%, 0%, 0%)", "rgb(100%, 0%, 0%)"),
+        ("hsla(120, 100%, 50%)", "hsl(120, 100%, 50%)"),
+        ("hsva(240, 100%, 100%)", "hsv(240, 100%, 100%)"),
+        ("hwba(60, 0%, 0%)", "hwb(60, 0%, 0%)"),
+    ];
+    for (alpha_function, function) in pairs.iter() {
+        let color = Color::new_string(*alpha_function).unwrap();
+        assert_eq!(color.alpha, 255, "{}", alpha_function);
+        assert_eq!(
+            color.channels(),
+            Color::new_string(*function).unwrap().channels(),
+            "{}",
+            alpha_function
+        );
+    }
+
+    // the functions without a trailing "a" accept a 4th value.
+    let transparent_green = Color::new_string("hsl(120, 100%, 50%, 0.25)").unwrap();
+    assert_eq!(transparent_green.alpha, 64);
+}
+
 #[test]
 fn color_new_string_hsv() {
     let red_color = Color::new_string("hsv(0, 100%, 100%)").unwrap();
@@ -870,6 +1250,326 @@ fn color_new_string_hwba() {
     assert_eq!(another_blue_color.alpha, 255);
 }
 
+#[test]
+fn color_new_string_css4_syntax() {
+    let red = Color::new_string("rgb(255 0 0)").unwrap();
+    assert_eq!(red.to_rgb_string(), "rgb(255, 0, 0)");
+
+    let transparent_green = Color::new_string("rgb(0 255 0 / 0.5)").unwrap();
+    assert_eq!(transparent_green.to_rgb_string(), "rgba(0, 255, 0, 0.5)");
+
+    let transparent_blue = Color::new_string("rgb(0% 0% 100% / 50%)").unwrap();
+    assert_eq!(transparent_blue.to_rgb_string(), "rgba(0, 0, 255, 0.5)");
+
+    let green = Color::new_string("hsl(120deg 100% 50%)").unwrap();
+    assert_eq!(green.to_hex_string(), "#00FF00");
+
+    let transparent_cyan = Color::new_string("hwb(180 0% 0% / 0.25)").unwrap();
+    assert_eq!(transparent_cyan.to_hex_string(), "#00FFFF40");
+
+    // the values must still be separated.
+    assert_eq!(
+        Color::new_string("rgb(255 0)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidCssFunction
+        })
+    );
+    assert_eq!(
+        Color::new_string("hsl(120 100%50%)"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidCssFunction
+        })
+    );
+
+    // the alpha value of gray() is the 2. value and cmyk() has no alpha value.
+    let transparent_gray = Color::new_string("gray(50% / 0.5)").unwrap();
+    assert_eq!([128, 128, 128, 128], transparent_gray.channels());
+    let red = Color::new_string("cmyk(0% 100% 100% 0%)").unwrap();
+    assert_eq!([255, 0, 0, 255], red.channels());
+
+    // commas and spaces can't be mixed and the alpha value must be separated by a slash.
+    for string in [
+        "rgb(255, 0 0)",
+        "rgb(0 255 0, 0.5)",
+        "rgb(0, 255, 0 / 0.5)",
+        "rgb(0 0 0 0.5)",
+        "rgb(0,0,0/0.5)",
+        "rgb(0 / 0 0)",
+        "hsl(120 100% / 50%)",
+        "gray(50 0.5)",
+        "cmyk(0% 100% 100% / 0%)",
+    ] {
+        assert_eq!(
+            Color::new_string(string),
+            Err(ParseError {
+                reason: ParseErrorEnum::InvalidCssFunction
+            }),
+            "{}",
+            string
+        );
+    }
+}
+
+#[test]
+fn color_new_string_hue_normalization() {
+    let hue_pairs = [
+        ("hsv(480, 100%, 100%)", "hsv(120, 100%, 100%)"),
+        ("hsv(-120, 100%, 100%)", "hsv(240, 100%, 100%)"),
+        ("hwb(-120, 0%, 0%)", "hwb(240, 0%, 0%)"),
+        ("hwb(480, 20%, 30%)", "hwb(120, 20%, 30%)"),
+        ("hsl(720, 100%, 50%)", "hsl(0, 100%, 50%)"),
+        ("hsl(-480, 100%, 50%)", "hsl(240, 100%, 50%)"),
+        ("hsl(1.5turn, 100%, 50%)", "hsl(180, 100%, 50%)"),
+        ("hwba(-0.25turn, 0%, 0%, 0.5)", "hwba(270, 0%, 0%, 0.5)"),
+    ];
+    for (unnormalized, normalized) in hue_pairs.iter() {
+        assert_eq!(
+            Color::new_string(*normalized).unwrap().get_rgba(),
+            Color::new_string(*unnormalized).unwrap().get_rgba(),
+            "{} should equal {}",
+            unnormalized,
+            normalized
+        );
+    }
+}
+
+#[test]
+fn color_new_string_lenient() {
+    let red = Color::new_string_lenient("rgb 255 0 0").unwrap();
+    assert_eq!(red.red, 255);
+    assert_eq!(red.green, 0);
+    assert_eq!(red.blue, 0);
+    assert_eq!(red.alpha, 255);
+    assert_eq!(red.get_original_string(), "rgb 255 0 0");
+
+    let green = Color::new_string_lenient("  HSL 120 100% 50%  ").unwrap();
+    assert_eq!(green.to_hex_string(), "#00FF00");
+
+    let transparent_blue = Color::new_string_lenient("rgba 0, 0, 255, 0.5").unwrap();
+    assert_eq!(transparent_blue.to_rgb_string(), "rgba(0, 0, 255, 0.5)");
+
+    let red = Color::new_string_lenient("oklab 0.628 0.2249 0.1258").unwrap();
+    assert_eq!([255, 0, 0, 255], red.channels());
+    let transparent_red = Color::new_string_lenient("lab 53.24, 80.09, 67.2, 50%").unwrap();
+    assert_eq!([255, 0, 0, 128], transparent_red.channels());
+
+    // everything the strict parser accepts is also accepted by the lenient parser.
+    assert_eq!(
+        Color::new_string_lenient("rgb(255, 0, 0)").unwrap(),
+        Color::new_string("rgb(255, 0, 0)").unwrap()
+    );
+    assert_eq!(
+        Color::new_string_lenient("red").unwrap().to_hex_string(),
+        "#FF0000"
+    );
+
+    assert_eq!(
+        Color::new_string("rgb 255 0 0"),
+        Err(ParseError {
+            reason: ParseErrorEnum::Unknown
+        })
+    );
+    assert_eq!(
+        Color::new_string_lenient("rgb 255 0"),
+        Err(ParseError {
+            reason: ParseErrorEnum::InvalidCssFunction
+        })
+    );
+    assert_eq!(
+        Color::new_string_lenient("not a color"),
+        Err(ParseError {
+            reason: ParseErrorEnum::Unknown
+        })
+    );
+}
+
+#[test]
+fn color_new_string_with_names() {
+    let mut names = HashMap::new();
+    names.insert(
+        String::from("Brand"),
+        Color::new_rgba(0x12, 0x34, 0x56, 0x78),
+    );
+    names.insert(String::from("red"), Color::new_rgb(0xAA, 0, 0));
+
+    let brand = Color::new_string_with_names("brand", &names).unwrap();
+    assert_eq!([0x12, 0x34, 0x56, 0x78], brand.channels());
+    assert_eq!("brand", brand.get_original_string());
+    let brand = Color::new_string_with_names(" BRAND ", &names).unwrap();
+    assert_eq!([0x12, 0x34, 0x56, 0x78], brand.channels());
+    // surrounding quotes are stripped like in new_string.
+    let brand = Color::new_string_with_names("\"Brand\"", &names).unwrap();
+    assert_eq!([0x12, 0x34, 0x56, 0x78], brand.channels());
+    assert_eq!("\"Brand\"", brand.get_original_string());
+
+    let mut mixed_case_names = HashMap::new();
+    mixed_case_names.insert(
+        String::from("Brand-Primary"),
+        Color::new_rgb(0xAB, 0xCD, 0xEF),
+    );
+    let brand_primary = Color::new_string_with_names("Brand-Primary", &mixed_case_names).unwrap();
+    assert_eq!("#ABCDEF", brand_primary.to_hex_string());
+    // keys, that only differ in case, prefer the lowercase key.
+    mixed_case_names.insert(
+        String::from("brand-primary"),
+        Color::new_rgb(0x12, 0x34, 0x56),
+    );
+    let brand_primary = Color::new_string_with_names("BRAND-PRIMARY", &mixed_case_names).unwrap();
+    assert_eq!("#123456", brand_primary.to_hex_string());
+
+    // custom color names take precedence over the standard names.
+    let red = Color::new_string_with_names("red", &names).unwrap();
+    assert_eq!("#AA0000", red.to_hex_string());
+
+    let blue = Color::new_string_with_names("blue", &names).unwrap();
+    assert_eq!("#0000FF", blue.to_hex_string());
+    let green = Color::new_string_with_names("rgb(0, 255, 0)", &names).unwrap();
+    assert_eq!("#00FF00", green.to_hex_string());
+
+    assert_eq!(
+        Color::new_string_with_names("brand-secondary", &names),
+        Err(ParseError {
+            reason: ParseErrorEnum::Unknown
+        })
+    );
+}
+
+#[test]
+fn color_new_string_quoted() {
+    let red = Color::new_string("\"#ff0000\"").unwrap();
+    assert_eq!(red.to_hex_string(), "#FF0000");
+    assert_eq!(red.get_original_string(), "\"#ff0000\"");
+
+    let red = Color::new_string("'red'").unwrap();
+    assert_eq!(red.to_hex_string(), "#FF0000");
+
+    let transparent_green = Color::new_string(" \" rgba(0, 255, 0, 0.5) \" ").unwrap();
+    assert_eq!(transparent_green.to_rgb_string(), "rgba(0, 255, 0, 0.5)");
+
+    // unquoted values still work.
+    let blue = Color::new_string("blue").unwrap();
+    assert_eq!(blue.to_hex_string(), "#0000FF");
+
+    // only a single pair of matching quotes is stripped.
+    assert!(Color::new_string("\"'red'\"").is_err());
+    assert!(Color::new_string("\"red'").is_err());
+    assert_eq!(
+        Color::new_string("\"\""),
+        Err(ParseError {
+            reason: ParseErrorEnum::EmptyString
+        })
+    );
+}
+
+#[test]
+fn color_new_string_with_options() {
+    let mut strict = ParseOptions::default();
+    strict.allow_quotes = false;
+    let parse = |string: &str, options: &ParseOptions| {
+        Color::new_string_with_options(string, options).map(|color| color.to_hex_string())
+    };
+
+    // allow_css_prop
+    let mut options = strict;
+    options.allow_css_prop = true;
+    for css_prop in ["color: red;", "background-color:#ff0000", "red;", "red,"] {
+        assert_eq!(Ok(String::from("#FF0000")), parse(css_prop, &options));
+        assert!(parse(css_prop, &strict).is_err(), "{}", css_prop);
+    }
+
+    // allow_quotes
+    let mut options = strict;
+    options.allow_quotes = true;
+    assert_eq!(Ok(String::from("#FF0000")), parse("'red'", &options));
+    assert!(parse("'red'", &strict).is_err());
+
+    // allow_0x
+    let mut options = strict;
+    options.allow_0x = true;
+    assert_eq!(Ok(String::from("#FF0000")), parse("0xFF0000", &options));
+    // values with an alpha channel are read as argb, like the output of to_argb_u32.
+    let transparent_red = Color::new_string_with_options("0x80FF0000", &options).unwrap();
+    assert_eq!([255, 0, 0, 128], transparent_red.channels());
+    let transparent_red = Color::new_string_with_options("0x8F00", &options).unwrap();
+    assert_eq!([255, 0, 0, 136], transparent_red.channels());
+    let color = Color::new_rgba(18, 52, 86, 120);
+    let argb = format!("0x{:08X}", color.to_argb_u32());
+    assert_eq!(
+        color.channels(),
+        Color::new_string_with_options(argb, &options)
+            .unwrap()
+            .channels()
+    );
+    assert_eq!(
+        parse("0xff0000", &strict),
+        Err(ParseError {
+            reason: ParseErrorEnum::Unknown
+        })
+    );
+
+    // allow_hex_gray
+    let mut options = strict;
+    options.allow_hex_gray = true;
+    assert_eq!(Ok(String::from("#888888")), parse("#8", &options));
+    assert_eq!(Ok(String::from("#808080")), parse("#80", &options));
+    assert_eq!(Ok(String::from("#ABABAB")), parse("#aB", &options));
+    assert_eq!(Ok(String::from("#FF0000")), parse("#f00", &options));
+    for hex_gray in ["#8", "#80"] {
+        assert!(parse(hex_gray, &strict).is_err(), "{}", hex_gray);
+        assert!(Color::new_string(hex_gray).is_err(), "{}", hex_gray);
+    }
+    assert!(parse("#8g", &options).is_err());
+
+    // allow_mixed_units
+    let mut options = strict;
+    options.allow_mixed_units = true;
+    assert_eq!(
+        Ok(String::from("#FF8000")),
+        parse("rgb(100%, 128, 0%)", &options)
+    );
+    assert_eq!(
+        parse("rgb(100%, 128, 0%)", &strict),
+        Err(ParseError {
+            reason: ParseErrorEnum::MixedUnits
+        })
+    );
+
+    // lenient_whitespace
+    let mut options = strict;
+    options.lenient_whitespace = true;
+    for whitespace in ["rgb 255 0 0", "rgb 255, 0, 0"] {
+        assert_eq!(Ok(String::from("#FF0000")), parse(whitespace, &options));
+        assert!(parse(whitespace, &strict).is_err(), "{}", whitespace);
+    }
+
+    // the default options are used by new_string.
+    for string in [
+        "'red'",
+        "color: red;",
+        "0xff0000",
+        "rgb(100%, 0, 0)",
+        "rgb 255 0 0",
+    ] {
+        assert_eq!(
+            Color::new_string(string).map(|color| color.to_hex_string()),
+            parse(string, &ParseOptions::default()),
+            "{}",
+            string
+        );
+    }
+
+    let mut all = ParseOptions::default();
+    all.allow_css_prop = true;
+    all.allow_quotes = true;
+    all.allow_0x = true;
+    all.allow_hex_gray = true;
+    all.allow_mixed_units = true;
+    all.lenient_whitespace = true;
+    let color = Color::new_string_with_options("color: \"0x80FF0000\";", &all).unwrap();
+    assert_eq!([255, 0, 0, 128], color.channels());
+    assert_eq!("color: \"0x80FF0000\";", color.get_original_string());
+}
+
 #[test]
 fn color_new_string_as_ref() {
     let red_str = "red";
@@ -901,6 +1601,27 @@ fn color_new_string_error_empty_string() {
             reason: ParseErrorEnum::EmptyString
         })
     );
+
+    // whitespace-only strings are empty after trimming.
+    for blank_string in ["   ", "\t", " \r\n "] {
+        assert_eq!(
+            Color::new_string(blank_string),
+            Err(ParseError {
+                reason: ParseErrorEnum::EmptyString
+            })
+        );
+        assert_eq!(
+            Color::new_string_lenient(blank_string),
+            Err(ParseError {
+                reason: ParseErrorEnum::EmptyString
+            })
+        );
+    }
+
+    // from_str reports the same reason, so empty strings can be told apart from unknown formats.
+    assert_eq!("".parse::<Color>(), Err(String::from("EmptyString")));
+    assert_eq!("   ".parse::<Color>(), Err(String::from("EmptyString")));
+    assert_eq!("foo bar".parse::<Color>(), Err(String::from("Unknown")));
 }
 
 #[test]
@@ -953,6 +1674,72 @@ fn color_new_string_error_unknown() {
     );
 }
 
+#[test]
+fn color_new_string_error_unexpected_angle_unit() {
+    for string in [
+        "rgb(120deg, 0, 0)",
+        "rgb(120°, 0, 0)",
+        "rgb(1rad, 0, 0)",
+        "rgba(0.5turn, 0, 0, 0.5)",
+        "cmyk(10deg, 0%, 0%, 0%)",
+        "gray(50grad)",
+    ] {
+        assert_eq!(
+            Color::new_string(string),
+            Err(ParseError {
+                reason: ParseErrorEnum::UnexpectedAngleUnit
+            }),
+            "{}",
+            string
+        );
+    }
+
+    let error = Color::new_string("rgb(120deg, 0, 0)").unwrap_err();
+    assert_eq!(
+        "UnexpectedAngleUnit: angle units (°, deg, grad, rad or turn) are only allowed for hue values",
+        error.to_string()
+    );
+}
+
+#[test]
+fn color_new_string_error_mixed_units() {
+    for rgb_string in [
+        "rgb(100%, 0, 0)",
+        "rgb(100%, 0%, 0)",
+        "rgb(255, 0%, 0)",
+        "rgba(255, 0, 100%, 0.5)",
+        "rgb(100% 0 0)",
+    ] {
+        assert_eq!(
+            Color::new_string(rgb_string),
+            Err(ParseError {
+                reason: ParseErrorEnum::MixedUnits
+            }),
+            "{}",
+            rgb_string
+        );
+    }
+
+    // the alpha value may use another unit than the rgb values.
+    assert!(Color::new_string("rgb(255, 0, 0, 50%)").is_ok());
+    assert!(Color::new_string("rgb(100%, 0%, 0%, 0.5)").is_ok());
+}
+
+#[test]
+fn color_new_string_hue_angle_units() {
+    let green = Color::new_string("hsl(120deg, 100%, 50%)").unwrap();
+    assert_eq!((green.red, green.green, green.blue), (0, 255, 0));
+
+    let cyan = Color::new_string("hsl(0.5turn, 100%, 50%)").unwrap();
+    assert_eq!((cyan.red, cyan.green, cyan.blue), (0, 255, 255));
+
+    let blue = Color::new_string("hsv(266.6667grad, 100%, 100%)").unwrap();
+    assert_eq!((blue.red, blue.green, blue.blue), (0, 0, 255));
+
+    let yellow = Color::new_string("hwb(1.0472rad, 0%, 0%)").unwrap();
+    assert_eq!((yellow.red, yellow.green, yellow.blue), (255, 255, 0));
+}
+
 #[test]
 fn color_new_temperature() {
     let temperature_1k = Color::new_temperature(1_000);
@@ -977,6 +1764,37 @@ fn color_new_temperature() {
     assert_eq!(temperature_30k.to_rgb_string(), "rgb(159, 190, 255)");
 }
 
+#[test]
+fn color_new_temperature_f64() {
+    assert_eq!(
+        Color::new_temperature(6_500),
+        Color::new_temperature_f64(6_500.0)
+    );
+    assert_eq!(
+        Color::new_temperature(30_000),
+        Color::new_temperature_f64(30_000.0)
+    );
+
+    // fractional temperatures lie between their integer neighbours.
+    let temperature_2k = Color::new_temperature_f64(2_000.0);
+    let temperature_2k5 = Color::new_temperature_f64(2_050.5);
+    let temperature_2k1 = Color::new_temperature_f64(2_100.0);
+    assert!(temperature_2k5.green > temperature_2k.green);
+    assert!(temperature_2k5.green < temperature_2k1.green);
+
+    // temperatures above 30000 Kelvin aren't capped and get more bluish.
+    let temperature_30k = Color::new_temperature_f64(30_000.0);
+    let temperature_35k = Color::new_temperature_f64(35_000.0);
+    assert_eq!(255, temperature_35k.blue);
+    assert!(temperature_35k.red < temperature_30k.red);
+    assert!(temperature_35k.red > 128);
+
+    assert_eq!(
+        Color::new_temperature(0),
+        Color::new_temperature_f64(-100.0)
+    );
+}
+
 #[test]
 fn color_to_temperature() {
     let temperature_1k = Color::new_string("rgb(255, 58, 0)").unwrap();
@@ -1003,6 +1821,77 @@ fn color_to_temperature() {
     assert_eq!(temperature_30k.to_temperature(), 28_244); // 30_000
 }
 
+#[test]
+fn color_to_argb_u32() {
+    let color = Color::new_rgba(0x12, 0x34, 0x56, 0x78);
+    assert_eq!(0x78123456, color.to_argb_u32());
+    assert_eq!(color.to_number() as u32, color.to_argb_u32());
+
+    let red = Color::new_string("red").unwrap();
+    assert_eq!(0xFFFF0000, red.to_argb_u32());
+}
+
+#[test]
+fn color_to_rgba_u32() {
+    let color = Color::new_rgba(0x12, 0x34, 0x56, 0x78);
+    assert_eq!(0x12345678, color.to_rgba_u32());
+
+    let red = Color::new_string("red").unwrap();
+    assert_eq!(0xFF0000FF, red.to_rgba_u32());
+}
+
+#[test]
+fn color_to_packed_u32() {
+    let color = Color::new_rgba(0x12, 0x34, 0x56, 0x78);
+    assert_eq!(0x78123456, color.to_packed_u32(ChannelOrder::Argb));
+    assert_eq!(0x12345678, color.to_packed_u32(ChannelOrder::Rgba));
+    assert_eq!(0x78563412, color.to_packed_u32(ChannelOrder::Abgr));
+    assert_eq!(0x56341278, color.to_packed_u32(ChannelOrder::Bgra));
+    assert_eq!(color.to_argb_u32(), color.to_packed_u32(ChannelOrder::Argb));
+    assert_eq!(color.to_rgba_u32(), color.to_packed_u32(ChannelOrder::Rgba));
+
+    for order in [
+        ChannelOrder::Argb,
+        ChannelOrder::Rgba,
+        ChannelOrder::Abgr,
+        ChannelOrder::Bgra,
+    ] {
+        let packed = color.to_packed_u32(order);
+        assert_eq!(
+            color.channels(),
+            Color::from_packed_u32(packed, order).channels()
+        );
+        assert_eq!(
+            packed,
+            Color::from_packed_u32(packed, order).to_packed_u32(order)
+        );
+    }
+}
+
+#[test]
+fn color_bucket_key() {
+    let color = Color::new_rgb(0x12, 0x34, 0x56);
+    assert_eq!(0x123456, color.bucket_key(8));
+    assert_eq!(0x135, color.bucket_key(4));
+    assert_eq!(0b000, color.bucket_key(1));
+    assert_eq!(0, color.bucket_key(0));
+    assert_eq!(color.bucket_key(8), color.bucket_key(9));
+    assert_eq!(color.bucket_key(4), color.clone().bucket_key(4));
+
+    // near-identical colors share a bucket at 4 bits, but not at 8 bits.
+    let near = Color::new_rgb(0x13, 0x35, 0x57);
+    assert_eq!(color.bucket_key(4), near.bucket_key(4));
+    assert_ne!(color.bucket_key(8), near.bucket_key(8));
+
+    // the alpha value is ignored.
+    let transparent = Color::new_rgba(0x12, 0x34, 0x56, 0);
+    assert_eq!(color.bucket_key(8), transparent.bucket_key(8));
+
+    let white = Color::new_string("white").unwrap();
+    assert_eq!(0xFFFFFF, white.bucket_key(8));
+    assert_eq!(0b111_111_111, white.bucket_key(3));
+}
+
 #[test]
 fn color_to_hex_string() {
     let red_color = Color::new_string("red").unwrap();
@@ -1012,6 +1901,46 @@ fn color_to_hex_string() {
     assert_eq!(transparent_green_color.to_hex_string(), "#00FF0080");
 }
 
+#[test]
+fn color_to_css_string() {
+    // a known color name is used, if it's shorter than the hex notation.
+    assert_eq!("red", Color::new_rgb(255, 0, 0).to_css_string());
+    assert_eq!("tan", Color::new_string("tan").unwrap().to_css_string());
+    assert_eq!("silver", Color::new_rgb(192, 192, 192).to_css_string());
+    // on a tie the hex notation wins.
+    assert_eq!("#00f", Color::new_rgb(0, 0, 255).to_css_string());
+    assert_eq!("#fff", Color::new_rgb(255, 255, 255).to_css_string());
+
+    assert_eq!(
+        "#fa0",
+        Color::new_string("#FFAA00").unwrap().to_css_string()
+    );
+    assert_eq!("#ffaa01", Color::new_rgb(255, 170, 1).to_css_string());
+    assert_eq!("#1a2b3c", Color::new_rgb(0x1a, 0x2b, 0x3c).to_css_string());
+
+    assert_eq!(
+        "rgba(255,0,0,0.5)",
+        Color::new_rgba(255, 0, 0, 128).to_css_string()
+    );
+    assert_eq!(
+        "rgba(255,0,0,0.004)",
+        Color::new_rgba(255, 0, 0, 1).to_css_string()
+    );
+    assert_eq!("transparent", Color::new_rgba(0, 0, 0, 0).to_css_string());
+
+    // the compact strings parse back to the same color.
+    for color in [
+        Color::new_rgb(255, 170, 0),
+        Color::new_rgb(1, 2, 3),
+        Color::new_rgba(10, 20, 30, 77),
+        Color::new_rgba(10, 20, 30, 1),
+        Color::new_rgb(255, 0, 0),
+    ] {
+        let parsed_color = Color::new_string(color.to_css_string()).unwrap();
+        assert_eq!(color.channels(), parsed_color.channels());
+    }
+}
+
 #[test]
 fn color_to_rgb_string() {
     let red_color = Color::new_string("red").unwrap();
@@ -1024,6 +1953,99 @@ fn color_to_rgb_string() {
     );
 }
 
+#[test]
+fn color_to_rgb_string_exact() {
+    let red_color = Color::new_string("red").unwrap();
+    assert_eq!(red_color.to_rgb_string_exact(), "rgb(255, 0, 0)");
+
+    let transparent_green_color = Color::new_string("#00FF0080").unwrap();
+    assert_eq!(
+        transparent_green_color.to_rgb_string_exact(),
+        "rgba(0, 255, 0, 0.502)"
+    );
+
+    // to_rgb_string is lossy for small alpha values.
+    let almost_transparent_blue = Color::new_rgba(0, 0, 255, 1);
+    assert_eq!(
+        almost_transparent_blue.to_rgb_string(),
+        "rgba(0, 0, 255, 0)"
+    );
+
+    for alpha in 0..=255 {
+        let color = Color::new_rgba(10, 20, 30, alpha);
+        let parsed_color = Color::new_string(color.to_rgb_string_exact()).unwrap();
+        assert_eq!(color.channels(), parsed_color.channels());
+    }
+}
+
+#[test]
+fn color_to_ansi_block() {
+    let orange = Color::new_rgb(255, 165, 0);
+    let block = orange.to_ansi_block(4);
+    assert!(block.starts_with("\x1b[48;2;255;165;0m"));
+    assert!(block.ends_with("\x1b[0m"));
+    assert_eq!("\x1b[48;2;255;165;0m    \x1b[0m", block);
+
+    // the alpha value is ignored.
+    let transparent_orange = Color::new_rgba(255, 165, 0, 128);
+    assert_eq!(block, transparent_orange.to_ansi_block(4));
+
+    assert_eq!("\x1b[48;2;255;165;0m\x1b[0m", orange.to_ansi_block(0));
+}
+
+#[test]
+fn color_to_string_styled() {
+    let red = Color::new_string("red").unwrap();
+    let transparent_green = Color::new_string("rgba(0, 255, 0, 0.5)").unwrap();
+
+    assert_eq!(
+        red.to_rgb_string_styled(FormatStyle::Legacy),
+        "rgb(255, 0, 0)"
+    );
+    assert_eq!(red.to_rgb_string_styled(FormatStyle::Css4), "rgb(255 0 0)");
+    assert_eq!(
+        transparent_green.to_rgb_string_styled(FormatStyle::Css4),
+        "rgb(0 255 0 / 0.5)"
+    );
+
+    assert_eq!(
+        red.to_hsl_string_styled(FormatStyle::Legacy),
+        red.to_hsl_string()
+    );
+    assert_eq!(
+        red.to_hsl_string_styled(FormatStyle::Css4),
+        "hsl(0 100% 50%)"
+    );
+    assert_eq!(
+        transparent_green.to_hsl_string_styled(FormatStyle::Legacy),
+        "hsla(120, 100%, 50%, 0.5)"
+    );
+    assert_eq!(
+        transparent_green.to_hsl_string_styled(FormatStyle::Css4),
+        "hsl(120 100% 50% / 0.5)"
+    );
+
+    assert_eq!(red.to_hwb_string_styled(FormatStyle::Css4), "hwb(0 0% 0%)");
+    assert_eq!(
+        transparent_green.to_hwb_string_styled(FormatStyle::Css4),
+        "hwb(120 0% 0% / 0.5)"
+    );
+
+    // the css4 output parses back to the same color.
+    for color in [red, transparent_green].iter() {
+        for css4_string in [
+            color.to_rgb_string_styled(FormatStyle::Css4),
+            color.to_hsl_string_styled(FormatStyle::Css4),
+            color.to_hwb_string_styled(FormatStyle::Css4),
+        ]
+        .iter()
+        {
+            let parsed_color = Color::new_string(css4_string.as_str()).unwrap();
+            assert_eq!(color.channels(), parsed_color.channels(), "{}", css4_string);
+        }
+    }
+}
+
 #[test]
 fn color_to_cmyk_string() {
     let red_color = Color::new_string("red").unwrap();
@@ -1082,6 +2104,78 @@ fn color_to_hwb_string() {
     );
 }
 
+#[test]
+fn color_to_oklab_and_oklch_string() {
+    let red = Color::new_string("red").unwrap();
+    assert_eq!(red.to_oklab_string(), "oklab(0.62796 0.22486 0.12585)");
+    assert_eq!(red.to_oklch_string(), "oklch(0.62796 0.25768 29.23)");
+
+    let transparent_blue = Color::new_rgba(0, 0, 255, 128);
+    assert_eq!(
+        transparent_blue.to_oklab_string(),
+        "oklab(0.45201 -0.03246 -0.31153 / 0.5)"
+    );
+
+    let white = Color::new_string("white").unwrap();
+    assert_eq!(white.to_oklab_string(), "oklab(1 0 0)");
+    assert_eq!(white.to_oklch_string(), "oklch(1 0 none)");
+    assert_eq!(
+        Color::new_string("black").unwrap().to_oklch_string(),
+        "oklch(0 0 none)"
+    );
+
+    // both formats parse back to the same color.
+    for color in [
+        Color::new_rgba(0, 0, 0, 128),
+        Color::new_rgba(255, 255, 255, 128),
+        Color::new_rgba(255, 0, 0, 128),
+        Color::new_rgba(0, 255, 0, 128),
+        Color::new_rgba(0, 0, 255, 128),
+        Color::new_rgba(0, 15, 30, 128),
+        Color::new_rgba(120, 45, 210, 128),
+        Color::new_rgba(240, 225, 15, 128),
+        Color::new_rgba(128, 128, 128, 128),
+    ] {
+        let oklab_string = color.to_oklab_string();
+        let oklch_string = color.to_oklch_string();
+        assert_eq!(
+            color.channels(),
+            Color::new_string(&oklab_string).unwrap().channels(),
+            "{}",
+            oklab_string
+        );
+        assert_eq!(
+            color.channels(),
+            Color::new_string(&oklch_string).unwrap().channels(),
+            "{}",
+            oklch_string
+        );
+    }
+}
+
+#[test]
+fn color_to_string_nearly_opaque() {
+    // an alpha value of 254 is rounded to 1, but must still be printed.
+    let red_color = Color::new_rgba(255, 0, 0, 254);
+    assert_eq!(red_color.to_rgb_string(), "rgba(255, 0, 0, 1)");
+    assert_eq!(red_color.to_hsl_string(), "hsla(0, 100%, 50%, 1)");
+    assert_eq!(red_color.to_hsv_string(), "hsva(0, 100%, 100%, 1)");
+    assert_eq!(red_color.to_hwb_string(), "hwba(0, 0%, 0%, 1)");
+    assert_eq!(red_color.to_hex_string(), "#FF0000FE");
+    assert_eq!(
+        red_color.to_rgb_string_styled(FormatStyle::Css4),
+        "rgb(255 0 0 / 1)"
+    );
+    assert_eq!(
+        red_color.to_hsl_string_styled(FormatStyle::Css4),
+        "hsl(0 100% 50% / 1)"
+    );
+    assert_eq!(
+        red_color.to_hwb_string_styled(FormatStyle::Css4),
+        "hwb(0 0% 0% / 1)"
+    );
+}
+
 #[test]
 fn color_colorize() {
     let white = Color::new_string("white").unwrap();
@@ -1159,6 +2253,20 @@ fn color_grayscale() {
     assert_eq!(255, grayscaled_blue.alpha);
 }
 
+#[test]
+fn color_grayscale_rounding() {
+    // 0.114 * 5 = 0.57, which would be 0 if floored.
+    let dark_blue = Color::new_rgb(0, 0, 5);
+    let grayscaled_dark_blue = dark_blue.grayscale();
+    assert_eq!(1, grayscaled_dark_blue.red);
+    assert_eq!(1, grayscaled_dark_blue.green);
+    assert_eq!(1, grayscaled_dark_blue.blue);
+
+    // 0.299 * 255 + 0.587 * 1 = 76.832, which would be 76 if floored.
+    let red = Color::new_rgb(255, 1, 0);
+    assert_eq!(77, red.grayscale().red);
+}
+
 #[test]
 fn color_grayscale_hdtv() {
     let color = Color::new_string("#FF7300").unwrap();
@@ -1215,6 +2323,54 @@ fn color_grayscale_hdr() {
     assert_eq!(255, grayscaled_blue.alpha);
 }
 
+#[test]
+fn color_grayscale_weighted() {
+    let red = Color::new_string("rgb(255, 0, 0)").unwrap();
+    assert_eq!(
+        "#4C4C4C",
+        red.grayscale_weighted(LumaStandard::Bt601).to_hex_string()
+    );
+    assert_eq!(
+        "#363636",
+        red.grayscale_weighted(LumaStandard::Bt709).to_hex_string()
+    );
+    assert_eq!(
+        "#434343",
+        red.grayscale_weighted(LumaStandard::Bt2100).to_hex_string()
+    );
+
+    // the named methods use the matching standard.
+    let color = Color::new_rgba(0xFF, 0x73, 0x00, 0x80);
+    assert_eq!(
+        color.grayscale(),
+        color.grayscale_weighted(LumaStandard::Bt601)
+    );
+    assert_eq!(
+        color.grayscale_hdtv(),
+        color.grayscale_weighted(LumaStandard::Bt709)
+    );
+    assert_eq!(
+        color.grayscale_hdr(),
+        color.grayscale_weighted(LumaStandard::Bt2100)
+    );
+    assert_eq!(color.grayscale_hdr(), color.grayscale_bt2100());
+    assert_eq!(0x80, color.grayscale_bt2100().alpha);
+
+    for standard in [
+        LumaStandard::Bt601,
+        LumaStandard::Bt709,
+        LumaStandard::Bt2100,
+    ] {
+        let weights = standard.weights();
+        assert!((weights.0 + weights.1 + weights.2 - 1.0).abs() < 1e-9);
+        let white = Color::new_string("white").unwrap();
+        assert_eq!(
+            "#FFFFFF",
+            white.grayscale_weighted(standard).to_hex_string()
+        );
+    }
+}
+
 #[test]
 fn color_monochrome() {
     let white = Color::new_string("white").unwrap();
@@ -1304,6 +2460,38 @@ fn color_invert() {
     assert_eq!("#003366", color10_inverted.to_hex_string());
 }
 
+#[test]
+fn color_invert_channels() {
+    let color = Color::new_string("#102030").unwrap();
+    assert_eq!(
+        "#EF2030",
+        color.invert_channels(true, false, false).to_hex_string()
+    );
+    assert_eq!(
+        "#10DF30",
+        color.invert_channels(false, true, false).to_hex_string()
+    );
+    assert_eq!(
+        "#1020CF",
+        color.invert_channels(false, false, true).to_hex_string()
+    );
+    assert_eq!(
+        color.channels(),
+        color.invert_channels(false, false, false).channels()
+    );
+    assert_eq!(color.invert(), color.invert_channels(true, true, true));
+
+    // the alpha value is never inverted.
+    let transparent_color = Color::new_string("#10203040").unwrap();
+    assert_eq!(
+        "#EF203040",
+        transparent_color
+            .invert_channels(true, false, false)
+            .to_hex_string()
+    );
+    assert_eq!("#EFDFCF40", transparent_color.invert().to_hex_string());
+}
+
 #[test]
 fn color_invert_luminescence() {
     let color = Color::new_string("#FF7300").unwrap();
@@ -1560,6 +2748,36 @@ fn color_lch_2_rgb() {
     assert_eq!(magenta_lch.to_rgb_string(), "rgb(255, 0, 255)");
 }
 
+#[test]
+fn color_lab_and_lch_structs() {
+    let red = Color::new_string("red").unwrap();
+    assert_eq!(
+        Lab {
+            l: 53.24,
+            a: 80.09,
+            b: 67.2
+        },
+        red.get_lab()
+    );
+    assert_eq!(
+        Lch {
+            l: 53.24,
+            c: 104.55,
+            h: 40.0
+        },
+        red.get_lch()
+    );
+    assert!(Color::new_gray(128).get_lch().h.is_nan());
+
+    for hex in [
+        "#000000", "#FFFFFF", "#808080", "#FF0000", "#00FF00", "#0000FF", "#123456", "#FEDCBA",
+    ] {
+        let color = Color::new_string(hex).unwrap();
+        assert_eq!(hex, Color::from_lab(color.get_lab()).to_hex_string());
+        assert_eq!(hex, Color::from_lch(color.get_lch()).to_hex_string());
+    }
+}
+
 #[test]
 fn color_interpolate_lch() {
     let red = Color::new_string("rgb(255, 0, 0)").unwrap();
@@ -1598,6 +2816,65 @@ fn color_brighten() {
     assert_eq!(color_brightened_10.to_hex_string(), "#FFFFFF");
 }
 
+#[test]
+fn color_darken_pct_and_lighten_pct() {
+    let red = Color::new_string("red").unwrap();
+    let black = Color::new_string("black").unwrap();
+    let white = Color::new_string("white").unwrap();
+
+    // color-mix(in srgb, red 80%, black) == rgb(204, 0, 0)
+    assert_eq!([204, 0, 0, 255], red.darken_pct(0.2).channels());
+    assert_eq!(
+        red.mix(&black, 0.2, MixSpace::Rgb).channels(),
+        red.darken_pct(0.2).channels()
+    );
+    // color-mix(in srgb, red 80%, white) == rgb(255, 51, 51)
+    assert_eq!([255, 51, 51, 255], red.lighten_pct(0.2).channels());
+    assert_eq!(
+        red.mix(&white, 0.2, MixSpace::Rgb).channels(),
+        red.lighten_pct(0.2).channels()
+    );
+
+    assert_eq!(red.channels(), red.darken_pct(0.0).channels());
+    assert_eq!(black.channels(), red.darken_pct(1.5).channels());
+    assert_eq!(white.channels(), red.lighten_pct(1.5).channels());
+
+    // unlike darken(...), the hue stays the same and the alpha value is kept.
+    assert_ne!(red.darken(1.0).channels(), red.darken_pct(0.2).channels());
+    let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    assert_eq!([204, 0, 0, 128], transparent_red.darken_pct(0.2).channels());
+    assert_eq!(
+        [255, 51, 51, 128],
+        transparent_red.lighten_pct(0.2).channels()
+    );
+}
+
+#[test]
+fn color_scale_value_hsv() {
+    let orange = Color::new_rgb(200, 110, 0);
+    let orange_hue = orange.get_hsva().0;
+
+    let scaled_orange = orange.scale_value_hsv(1.5);
+    assert_eq!(255, scaled_orange.red);
+    assert!((scaled_orange.get_hsva().0 - orange_hue).abs() < 0.5);
+    assert_eq!(orange.get_hsva().1, scaled_orange.get_hsva().1);
+
+    // naive scaling of the rgb channels clips the red channel and shifts the hue.
+    let naive_scaled_orange = Color::new_rgb(
+        (orange.red as f64 * 1.5).min(255.0).round() as u8,
+        (orange.green as f64 * 1.5).min(255.0).round() as u8,
+        (orange.blue as f64 * 1.5).min(255.0).round() as u8,
+    );
+    assert!((naive_scaled_orange.get_hsva().0 - orange_hue).abs() > 5.0);
+
+    let darkened_orange = orange.scale_value_hsv(0.5);
+    assert_eq!("#643700", darkened_orange.to_hex_string());
+    assert_eq!("#000000", orange.scale_value_hsv(0.0).to_hex_string());
+
+    let transparent_orange = Color::new_rgba(200, 110, 0, 128);
+    assert_eq!(128, transparent_orange.scale_value_hsv(1.5).alpha);
+}
+
 #[test]
 fn color_to_number() {
     let black = Color::new_string("#000000").unwrap();
@@ -1653,6 +2930,37 @@ fn color_from_number() {
     assert_eq!(half_transparent_black.to_hex_string(), "#00000080");
 }
 
+#[test]
+fn color_channels() {
+    let color = Color::new_rgba(0x12, 0x34, 0x56, 0x78);
+    assert_eq!([0x12, 0x34, 0x56, 0x78], color.channels());
+
+    let mut sum = 0;
+    for channel in &color {
+        sum += channel as u16;
+    }
+    assert_eq!(0x12 + 0x34 + 0x56 + 0x78, sum);
+
+    let channels: Vec<u8> = color.into_iter().collect();
+    assert_eq!(vec![0x12, 0x34, 0x56, 0x78], channels);
+}
+
+#[test]
+fn color_channel_extremes() {
+    let color = Color::new_rgba(0x12, 0x56, 0x34, 0x00);
+    assert_eq!(0x56, color.max_channel());
+    assert_eq!(0x12, color.min_channel());
+    assert_eq!(0x44, color.chroma_rgb());
+
+    let red = Color::new_rgb(255, 0, 0);
+    assert_eq!(255, red.chroma_rgb());
+
+    let gray = Color::new_gray(128);
+    assert_eq!(128, gray.max_channel());
+    assert_eq!(128, gray.min_channel());
+    assert_eq!(0, gray.chroma_rgb());
+}
+
 #[test]
 fn color_get_luminance() {
     let white = Color::new_string("white").unwrap();
@@ -1668,6 +2976,23 @@ fn color_get_luminance() {
     assert_eq!(black.get_luminance(), 0.0);
 }
 
+#[test]
+fn color_luminance_with_gamma() {
+    let white = Color::new_string("white").unwrap();
+    let black = Color::new_string("black").unwrap();
+    assert_eq!(white.luminance_with_gamma(2.2), 1.0);
+    assert_eq!(black.luminance_with_gamma(2.2), 0.0);
+
+    // a gamma of 2.2 is close to srgb, but slightly brighter in the midtones.
+    let gray = Color::new_rgb(128, 128, 128);
+    let srgb_luminance = gray.get_luminance();
+    let gamma_luminance = gray.luminance_with_gamma(2.2);
+    assert_eq!(0.21586050011389923, srgb_luminance);
+    assert_eq!(0.21952, (gamma_luminance * 100000.0).round() / 100000.0);
+    assert!(gamma_luminance > srgb_luminance);
+    assert!(gamma_luminance - srgb_luminance < 0.005);
+}
+
 #[test]
 fn color_get_contrast() {
     let pink = Color::new_string("pink").unwrap();
@@ -1678,6 +3003,115 @@ fn color_get_contrast() {
     assert_eq!(pink.get_contrast(purple), 6.124225406859997);
 }
 
+#[test]
+fn color_blend_over() {
+    let white = Color::new_string("white").unwrap();
+    let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    let opaque_blue = Color::new_string("blue").unwrap();
+    let transparent = Color::new_string("transparent").unwrap();
+
+    assert_eq!(
+        "#FF7F7F",
+        transparent_red.blend_over(&white).to_hex_string()
+    );
+    assert_eq!("#0000FF", opaque_blue.blend_over(&white).to_hex_string());
+    assert_eq!("#FFFFFF", transparent.blend_over(&white).to_hex_string());
+    assert_eq!(
+        "#00000000",
+        transparent.blend_over(&transparent).to_hex_string()
+    );
+    assert_eq!(
+        "#FF0000C0",
+        transparent_red.blend_over(&transparent_red).to_hex_string()
+    );
+}
+
+#[test]
+fn color_over_checkerboard() {
+    let white = Color::new_string("white").unwrap();
+    let gray = Color::new_string("gray").unwrap();
+    let transparent_red = Color::new_rgba(255, 0, 0, 128);
+
+    let (over_white, over_gray) = transparent_red.over_checkerboard(&white, &gray);
+    assert_eq!("#FF7F7F", over_white.to_hex_string());
+    assert_eq!("#C04040", over_gray.to_hex_string());
+    assert_eq!(255, over_white.alpha);
+    assert_eq!(255, over_gray.alpha);
+
+    // opaque colors look the same on both tiles.
+    let red = Color::new_string("red").unwrap();
+    let (over_white, over_gray) = red.over_checkerboard(&white, &gray);
+    assert_eq!(over_white, over_gray);
+    assert_eq!("#FF0000", over_white.to_hex_string());
+}
+
+#[test]
+fn color_contrast_composited() {
+    let white = Color::new_string("white").unwrap();
+    let black = Color::new_string("black").unwrap();
+    let transparent_black = Color::new_rgba(0, 0, 0, 26);
+
+    let opaque_contrast = black.contrast_composited(&white, &white);
+    let transparent_contrast = transparent_black.contrast_composited(&white, &white);
+
+    assert_eq!(opaque_contrast, black.get_contrast(white.clone()));
+    assert_eq!(transparent_black.get_contrast(white.clone()), 21.0);
+    assert!(transparent_contrast < 1.5);
+    assert!(transparent_contrast < opaque_contrast / 10.0);
+}
+
+#[test]
+fn color_best_background() {
+    let black = Color::new_string("black").unwrap();
+    let backgrounds = [
+        Color::new_string("navy").unwrap(),
+        Color::new_string("gray").unwrap(),
+        Color::new_string("lightyellow").unwrap(),
+        Color::new_string("silver").unwrap(),
+    ];
+    assert_eq!(Some(&backgrounds[2]), black.best_background(&backgrounds));
+
+    // the first candidate wins on a tie.
+    let whites = [
+        Color::new_rgb(255, 255, 255),
+        Color::new_rgba(255, 255, 255, 0),
+    ];
+    let best = black.best_background(&whites).unwrap();
+    assert!(std::ptr::eq(best, &whites[0]));
+
+    assert_eq!(None, black.best_background(&[]));
+}
+
+#[test]
+fn color_sort_perceptual() {
+    let red = Color::new_string("red").unwrap();
+    let blue = Color::new_string("blue").unwrap();
+    let gradient: Vec<Color> = (0..10)
+        .map(|i| red.interpolate_lab(blue.clone(), i as f64 / 9.0))
+        .collect();
+    let max_step = gradient
+        .windows(2)
+        .map(|pair| pair[0].get_delta_e(&pair[1]))
+        .fold(0.0, f64::max);
+
+    let mut shuffled: Vec<Color> = [3, 7, 0, 9, 5, 1, 8, 2, 6, 4]
+        .iter()
+        .map(|&i| gradient[i].clone())
+        .collect();
+    Color::sort_perceptual(&mut shuffled);
+
+    for pair in shuffled.windows(2) {
+        assert!(pair[0].get_delta_e(&pair[1]) <= max_step);
+    }
+    // blue is darker than red, so the order begins with blue.
+    assert_eq!(gradient[9], shuffled[0]);
+    assert_eq!(gradient[0], shuffled[9]);
+
+    let mut empty: Vec<Color> = vec![];
+    Color::sort_perceptual(&mut empty);
+    assert!(empty.is_empty());
+}
+
 #[test]
 fn color_get_original_string() {
     let red = Color::new_string("red").unwrap();
@@ -1720,3 +3154,672 @@ fn color_mix_subtractive() {
     assert_eq!("#FF0000", red.to_hex_string());
     assert_eq!("#000000", black.to_hex_string());
 }
+
+#[test]
+fn color_interpolate_linear() {
+    let red = Color::new_string("red").unwrap();
+    let green = Color::new_string("lime").unwrap();
+
+    let gamma_encoded_mix = red.interpolate(green.clone(), 0.5);
+    let linear_mix = red.interpolate_linear(green.clone(), 0.5);
+
+    assert_eq!("rgb(128, 128, 0)", gamma_encoded_mix.to_rgb_string());
+    assert_eq!("rgb(188, 188, 0)", linear_mix.to_rgb_string());
+    assert!(linear_mix.get_luminance() > gamma_encoded_mix.get_luminance());
+
+    assert_eq!(
+        "#FF0000",
+        red.interpolate_linear(green.clone(), 0.0).to_hex_string()
+    );
+    assert_eq!(
+        "#00FF00",
+        red.interpolate_linear(green.clone(), 1.0).to_hex_string()
+    );
+
+    let transparent_green = Color::new_rgba(0, 255, 0, 0);
+    assert_eq!(128, red.interpolate_linear(transparent_green, 0.5).alpha);
+}
+
+#[test]
+fn color_interpolate_many() {
+    let red = Color::new_rgb(255, 0, 0);
+    let transparent_blue = Color::new_rgba(0, 0, 255, 0);
+
+    let colors = red.interpolate_many(&transparent_blue, 3);
+    assert_eq!(3, colors.len());
+    assert_eq!(red, colors[0]);
+    assert_eq!(red.interpolate(transparent_blue.clone(), 0.5), colors[1]);
+    assert_eq!(transparent_blue, colors[2]);
+
+    let colors = red.interpolate_many(&transparent_blue, 11);
+    assert_eq!(11, colors.len());
+    for (index, color) in colors.iter().enumerate() {
+        let expected = red.interpolate(transparent_blue.clone(), index as f64 / 10.0);
+        assert_eq!(expected, *color, "{}", index);
+    }
+
+    assert_eq!(
+        vec![red.clone()],
+        red.interpolate_many(&transparent_blue, 1)
+    );
+    assert!(red.interpolate_many(&transparent_blue, 0).is_empty());
+}
+
+#[test]
+fn color_interpolate_lab() {
+    let white = Color::new_string("white").unwrap();
+    let black = Color::new_string("black").unwrap();
+
+    assert_eq!(
+        "#FFFFFF",
+        white.interpolate_lab(black.clone(), 0.0).to_hex_string()
+    );
+    assert_eq!(
+        "#777777",
+        white.interpolate_lab(black.clone(), 0.5).to_hex_string()
+    );
+    assert_eq!("#000000", white.interpolate_lab(black, 1.0).to_hex_string());
+}
+
+#[test]
+fn color_mix() {
+    let red = Color::new_string("red").unwrap();
+    let blue = Color::new_string("blue").unwrap();
+
+    assert_eq!(
+        red.interpolate(blue.clone(), 0.3),
+        red.mix(&blue, 0.3, MixSpace::Rgb)
+    );
+    assert_eq!(
+        red.interpolate_hsl(blue.clone(), 0.3),
+        red.mix(&blue, 0.3, MixSpace::Hsl)
+    );
+    assert_eq!(
+        red.interpolate_hsv(blue.clone(), 0.3),
+        red.mix(&blue, 0.3, MixSpace::Hsv)
+    );
+    assert_eq!(
+        red.interpolate_hwb(blue.clone(), 0.3),
+        red.mix(&blue, 0.3, MixSpace::Hwb)
+    );
+    assert_eq!(
+        red.interpolate_lab(blue.clone(), 0.3),
+        red.mix(&blue, 0.3, MixSpace::Lab)
+    );
+    assert_eq!(
+        red.interpolate_lch(blue.clone(), 0.3),
+        red.mix(&blue, 0.3, MixSpace::Lch)
+    );
+}
+
+#[test]
+fn color_mix_alpha() {
+    let faint_red = Color::new_rgba(255, 0, 0, 51);
+    let blue = Color::new_string("blue").unwrap();
+
+    for space in [
+        MixSpace::Rgb,
+        MixSpace::Hsl,
+        MixSpace::Hsv,
+        MixSpace::Hwb,
+        MixSpace::Lab,
+        MixSpace::Lch,
+    ] {
+        // the alpha value is the linear midpoint (51 + 255) / 2, independent of the color space.
+        assert_eq!(153, faint_red.mix(&blue, 0.5, space).alpha);
+        assert_eq!(102, faint_red.mix(&blue, 0.25, space).alpha);
+        assert_eq!(51, faint_red.mix(&blue, 0.0, space).alpha);
+        assert_eq!(255, faint_red.mix(&blue, 1.0, space).alpha);
+    }
+}
+
+#[test]
+fn color_try_mix() {
+    let red = Color::new_string("red").unwrap();
+    let blue = Color::new_string("blue").unwrap();
+
+    for weight in [0.0, 0.3, 1.0] {
+        assert_eq!(
+            Ok(red.mix(&blue, weight, MixSpace::Lab)),
+            red.try_mix(&blue, weight, MixSpace::Lab)
+        );
+    }
+
+    for weight in [-0.1, 1.1, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            Err(ParseError {
+                reason: ParseErrorEnum::InvalidWeight
+            }),
+            red.try_mix(&blue, weight, MixSpace::Rgb),
+            "{}",
+            weight
+        );
+    }
+
+    // mix still clamps the weight.
+    assert_eq!(
+        blue.channels(),
+        red.mix(&blue, 1.1, MixSpace::Rgb).channels()
+    );
+}
+
+#[test]
+fn color_mix_all_spaces() {
+    let red = Color::new_string("red").unwrap();
+    let blue = Color::new_string("blue").unwrap();
+
+    let mixes = red.mix_all_spaces(&blue, 0.3);
+    assert_eq!(6, mixes.len());
+    for space in [
+        MixSpace::Rgb,
+        MixSpace::Hsl,
+        MixSpace::Hsv,
+        MixSpace::Hwb,
+        MixSpace::Lab,
+        MixSpace::Lch,
+    ] {
+        assert_eq!(red.mix(&blue, 0.3, space), mixes[&space], "{:?}", space);
+    }
+    assert_eq!(red.interpolate(blue.clone(), 0.3), mixes[&MixSpace::Rgb]);
+}
+
+#[test]
+fn color_mix_with_alpha_mode() {
+    let transparent_red = Color::new_rgba(255, 0, 0, 0);
+    let blue = Color::new_string("blue").unwrap();
+
+    // straight alpha: the invisible red still tints the result.
+    let straight =
+        transparent_red.mix_with_alpha_mode(&blue, 0.5, MixSpace::Rgb, AlphaMode::Straight);
+    assert_eq!(straight.red, 128);
+    assert_eq!(straight.green, 0);
+    assert_eq!(straight.blue, 128);
+    assert_eq!(straight.alpha, 128);
+    assert_eq!(straight, transparent_red.mix(&blue, 0.5, MixSpace::Rgb));
+
+    // premultiplied alpha: the hue of the opaque color is preserved.
+    for space in [MixSpace::Rgb, MixSpace::Hsl, MixSpace::Lab, MixSpace::Lch] {
+        let premultiplied =
+            transparent_red.mix_with_alpha_mode(&blue, 0.5, space, AlphaMode::Premultiplied);
+        assert_eq!(premultiplied.red, 0);
+        assert_eq!(premultiplied.green, 0);
+        assert_eq!(premultiplied.blue, 255);
+        assert_eq!(premultiplied.alpha, 128);
+    }
+
+    // with two opaque colors both modes are identical.
+    let red = Color::new_string("red").unwrap();
+    assert_eq!(
+        red.mix_with_alpha_mode(&blue, 0.3, MixSpace::Rgb, AlphaMode::Straight),
+        red.mix_with_alpha_mode(&blue, 0.3, MixSpace::Rgb, AlphaMode::Premultiplied)
+    );
+}
+
+#[test]
+fn color_perceptual_midpoint() {
+    let white = Color::new_string("white").unwrap();
+    let black = Color::new_string("black").unwrap();
+
+    let midpoint = black.perceptual_midpoint(&white);
+    let srgb_midpoint = black.interpolate(white, 0.5);
+
+    assert_eq!(midpoint.red, midpoint.green);
+    assert_eq!(midpoint.green, midpoint.blue);
+    assert!((midpoint.get_laba().0 - 50.0).abs() < 0.5);
+    assert_eq!("#777777", midpoint.to_hex_string());
+    assert_eq!("#808080", srgb_midpoint.to_hex_string());
+    // the lab midpoint is darker than the sRGB midpoint.
+    assert!(midpoint.get_luminance() < srgb_midpoint.get_luminance() - 0.02);
+}
+
+#[test]
+fn color_get_delta_e() {
+    let red = Color::new_string("red").unwrap();
+    let dark_red = Color::new_string("darkred").unwrap();
+    let white = Color::new_string("white").unwrap();
+    let black = Color::new_string("black").unwrap();
+
+    assert_eq!(red.get_delta_e(&red), 0.0);
+    assert_eq!(red.get_delta_e(&dark_red), dark_red.get_delta_e(&red));
+    assert_eq!((red.get_delta_e(&dark_red) * 100.0).round() / 100.0, 23.75);
+    assert_eq!(white.get_delta_e(&black).round(), 100.0);
+}
+
+#[test]
+fn color_clamp_to_cmyk_gamut() {
+    let blue = Color::new_string("blue").unwrap();
+    let printable_blue = blue.clamp_to_cmyk_gamut();
+    let blue_lcha = blue.get_lcha();
+    let printable_blue_lcha = printable_blue.get_lcha();
+
+    assert!(printable_blue_lcha.1 < blue_lcha.1 * 0.6);
+    assert!((printable_blue_lcha.0 - blue_lcha.0).abs() < 1.0);
+    assert!((printable_blue_lcha.2 - blue_lcha.2).abs() < 1.0);
+    assert_eq!("#55399B", printable_blue.to_hex_string());
+
+    let muted_blue = Color::new_rgb(70, 80, 140);
+    assert_eq!(muted_blue, muted_blue.clamp_to_cmyk_gamut());
+
+    // the gamut is narrower for dark and light colors of the same hue.
+    let navy = Color::new_string("navy").unwrap();
+    let light_blue = Color::new_rgb(200, 200, 255);
+    assert!(navy.clamp_to_cmyk_gamut().get_lcha().1 < printable_blue_lcha.1 * 0.6);
+    assert!(light_blue.clamp_to_cmyk_gamut().get_lcha().1 < light_blue.get_lcha().1);
+}
+
+#[test]
+fn color_is_printable() {
+    let blue = Color::new_string("blue").unwrap();
+    assert!(!blue.is_printable());
+    assert!(blue.clamp_to_cmyk_gamut().is_printable());
+
+    for printable in [
+        Color::new_rgb(70, 80, 140),
+        Color::new_rgb(90, 110, 150),
+        Color::new_string("gray").unwrap(),
+        Color::new_string("white").unwrap(),
+        Color::new_string("black").unwrap(),
+    ] {
+        assert!(printable.is_printable(), "{}", printable.to_hex_string());
+    }
+}
+
+#[test]
+fn color_gamut_warning_color() {
+    let blue = Color::new_string("blue").unwrap();
+    let yellow = Color::new_string("yellow").unwrap();
+    assert_eq!("#FFFFFF", blue.gamut_warning_color().to_hex_string());
+    assert_eq!("#000000", yellow.gamut_warning_color().to_hex_string());
+
+    // the overlay color is opaque and has at least a contrast of 4.5:1.
+    let transparent_blue = Color::new_rgba(0, 0, 255, 0);
+    assert_eq!(255, transparent_blue.gamut_warning_color().alpha);
+    for red in (0..=255).step_by(51) {
+        for green in (0..=255).step_by(51) {
+            for blue in (0..=255).step_by(51) {
+                let color = Color::new_rgb(red, green, blue);
+                assert!(color.get_contrast(color.gamut_warning_color()) >= 4.5);
+            }
+        }
+    }
+}
+
+#[test]
+fn color_vibrant_and_muted() {
+    let dusty_blue = Color::new_rgb(90, 110, 150);
+    let dusty_blue_lcha = dusty_blue.get_lcha();
+
+    let vibrant_blue = dusty_blue.vibrant();
+    let vibrant_blue_lcha = vibrant_blue.get_lcha();
+    assert!(vibrant_blue_lcha.1 > dusty_blue_lcha.1);
+    assert!(vibrant_blue_lcha.0 >= 39.5 && vibrant_blue_lcha.0 <= 70.5);
+    assert!((vibrant_blue_lcha.2 - dusty_blue_lcha.2).abs() < 2.0);
+
+    let muted_blue = dusty_blue.muted();
+    let muted_blue_lcha = muted_blue.get_lcha();
+    assert!(muted_blue_lcha.1 < dusty_blue_lcha.1);
+    assert!((muted_blue_lcha.0 - dusty_blue_lcha.0).abs() < 1.0);
+
+    // the chroma of red is already at the border of the sRGB gamut.
+    let red = Color::new_string("red").unwrap();
+    let vibrant_red = red.vibrant();
+    assert!((vibrant_red.get_lcha().1 - red.get_lcha().1).abs() < 1.0);
+    assert!(red.muted().get_lcha().1 < red.get_lcha().1);
+
+    // light saturated colors keep their lightness instead of losing chroma.
+    for name in ["yellow", "lime", "cyan"] {
+        let color = Color::new_string(name).unwrap();
+        let vibrant_color = color.vibrant();
+        assert!(
+            vibrant_color.get_lcha().1 >= color.get_lcha().1 - 0.5,
+            "{}",
+            name
+        );
+        assert!((vibrant_color.get_lcha().0 - color.get_lcha().0).abs() < 1.0);
+    }
+    let light_green = Color::new_rgb(150, 230, 150);
+    assert!(light_green.vibrant().get_lcha().1 > light_green.get_lcha().1);
+
+    let gray = Color::new_string("gray").unwrap();
+    assert_eq!(gray, gray.vibrant());
+    assert_eq!(gray, gray.muted());
+
+    let transparent_blue = Color::new_rgba(90, 110, 150, 128);
+    assert_eq!(128, transparent_blue.vibrant().alpha);
+    assert_eq!(128, transparent_blue.muted().alpha);
+}
+
+#[test]
+fn color_vibrance() {
+    let muted_blue = Color::new_rgb(90, 110, 150);
+    let vivid_green = Color::new_rgb(20, 230, 40);
+
+    let muted_gain = muted_blue.vibrance(0.5, false).get_hsla().1 - muted_blue.get_hsla().1;
+    let vivid_gain = vivid_green.vibrance(0.5, false).get_hsla().1 - vivid_green.get_hsla().1;
+    assert!(muted_gain > 0.0);
+    assert!(vivid_gain >= 0.0);
+    assert!(muted_gain > vivid_gain);
+
+    // the lightness and the hue are preserved.
+    let vibrant_blue = muted_blue.vibrance(0.5, false);
+    assert!((vibrant_blue.get_hsla().2 - muted_blue.get_hsla().2).abs() < 0.01);
+    assert!((vibrant_blue.get_hsla().0 - muted_blue.get_hsla().0).abs() < 1.0);
+
+    let skin = Color::new_rgb(224, 172, 138);
+    assert_eq!(skin, skin.vibrance(0.5, true));
+    assert!(skin.vibrance(0.5, false).get_hsla().1 > skin.get_hsla().1);
+
+    let gray = Color::new_string("gray").unwrap();
+    assert_eq!(gray, gray.vibrance(0.5, false));
+    assert!(muted_blue.vibrance(-0.5, false).get_hsla().1 < muted_blue.get_hsla().1);
+
+    // a negative amount lowers the saturation of vivid colors more than of muted colors.
+    let muted_loss = muted_blue.get_hsla().1 - muted_blue.vibrance(-0.5, false).get_hsla().1;
+    let vivid_loss = vivid_green.get_hsla().1 - vivid_green.vibrance(-0.5, false).get_hsla().1;
+    assert!(muted_loss > 0.0);
+    assert!(vivid_loss > muted_loss);
+
+    let transparent_blue = Color::new_rgba(90, 110, 150, 128);
+    assert_eq!(128, transparent_blue.vibrance(0.5, false).alpha);
+}
+
+#[test]
+fn color_diff() {
+    let red = Color::new_string("red").unwrap();
+    let transparent_blue = Color::new_rgba(0, 0, 255, 128);
+    let diff = red.diff(&transparent_blue);
+
+    assert_eq!(-255, diff.red);
+    assert_eq!(0, diff.green);
+    assert_eq!(255, diff.blue);
+    assert_eq!(-127, diff.alpha);
+    assert_eq!(52.88, (diff.delta_e * 100.0).round() / 100.0);
+    // lch hue of red: 40.0, lch hue of blue: 306.29 -> 266.29° is shorter the other way round.
+    assert_eq!(40.0, red.get_lcha().2);
+    assert_eq!(306.29, transparent_blue.get_lcha().2);
+    assert_eq!(-93.71, diff.hue);
+    assert_eq!(93.71, transparent_blue.diff(&red).hue);
+
+    let gray = Color::new_string("gray").unwrap();
+    let gray_diff = gray.diff(&red);
+    assert_eq!(127, gray_diff.red);
+    assert_eq!(-128, gray_diff.green);
+    assert_eq!(-128, gray_diff.blue);
+    assert_eq!(0, gray_diff.alpha);
+    assert_eq!(0.0, gray_diff.hue);
+
+    let same_diff = red.diff(&red);
+    assert_eq!(0, same_diff.red);
+    assert_eq!(0.0, same_diff.delta_e);
+    assert_eq!(0.0, same_diff.hue);
+}
+
+#[test]
+fn color_hue_sector() {
+    let sectors = [
+        ("red", HueSector::Red),
+        ("rgb(255, 128, 0)", HueSector::Orange),
+        ("yellow", HueSector::Yellow),
+        ("lime", HueSector::Green),
+        ("cyan", HueSector::Cyan),
+        ("blue", HueSector::Blue),
+        ("rgb(128, 0, 255)", HueSector::Purple),
+        ("magenta", HueSector::Magenta),
+        ("hsl(350, 100%, 50%)", HueSector::Red),
+        ("hsl(15, 100%, 50%)", HueSector::Orange),
+        ("hsl(344, 100%, 50%)", HueSector::Magenta),
+    ];
+    for (color_string, sector) in sectors.iter() {
+        let color = Color::new_string(*color_string).unwrap();
+        assert_eq!(Some(*sector), color.hue_sector(), "{}", color_string);
+    }
+
+    assert_eq!(None, Color::new_string("white").unwrap().hue_sector());
+    assert_eq!(None, Color::new_string("gray").unwrap().hue_sector());
+    assert_eq!(None, Color::new_string("black").unwrap().hue_sector());
+}
+
+#[test]
+fn color_match_lightness() {
+    let orange = Color::new_rgb(230, 120, 30);
+    let dark_gray = Color::new_rgb(60, 60, 60);
+    let orange_lcha = orange.get_lcha();
+    let dark_gray_lcha = dark_gray.get_lcha();
+
+    let dark_orange = orange.match_lightness(&dark_gray);
+    let dark_orange_lcha = dark_orange.get_lcha();
+    assert!((dark_orange_lcha.0 - dark_gray_lcha.0).abs() < 0.5);
+    assert!((dark_orange_lcha.2 - orange_lcha.2).abs() < 2.0);
+
+    // the chroma of a light blue doesn't fit into the gamut at a high lightness.
+    let blue = Color::new_string("blue").unwrap();
+    let white_smoke = Color::new_string("whitesmoke").unwrap();
+    let light_blue = blue.match_lightness(&white_smoke);
+    let light_blue_lcha = light_blue.get_lcha();
+    assert!((light_blue_lcha.0 - white_smoke.get_lcha().0).abs() < 0.5);
+    assert!((light_blue_lcha.2 - blue.get_lcha().2).abs() < 2.0);
+    assert!(light_blue_lcha.1 < blue.get_lcha().1);
+
+    let transparent_orange = Color::new_rgba(230, 120, 30, 128);
+    assert_eq!(128, transparent_orange.match_lightness(&dark_gray).alpha);
+    assert_eq!(orange, orange.match_lightness(&orange));
+}
+
+#[test]
+fn color_is_light() {
+    assert!(Color::new_string("white").unwrap().is_light());
+    assert!(Color::new_string("yellow").unwrap().is_light());
+    assert!(Color::new_string("lightgray").unwrap().is_light());
+    assert!(!Color::new_string("black").unwrap().is_light());
+    assert!(!Color::new_string("navy").unwrap().is_light());
+    assert!(!Color::new_string("darkred").unwrap().is_light());
+}
+
+#[test]
+fn color_auto_border() {
+    let light_fill = Color::new_string("lightyellow").unwrap();
+    let light_fill_border = light_fill.auto_border(0.5);
+    assert!(light_fill.get_lcha().0 - light_fill_border.get_lcha().0 > 5.0);
+    assert_eq!(light_fill.darken(0.5), light_fill_border);
+
+    let dark_fill = Color::new_string("navy").unwrap();
+    let dark_fill_border = dark_fill.auto_border(0.5);
+    assert!(dark_fill_border.get_lcha().0 - dark_fill.get_lcha().0 > 5.0);
+    assert_eq!(dark_fill.brighten(0.5), dark_fill_border);
+}
+
+#[test]
+fn color_simulate_color_blindness() {
+    let white = Color::new_rgb(255, 255, 255);
+    let black = Color::new_rgb(0, 0, 0);
+    let red = Color::new_string("red").unwrap();
+    let green = Color::new_string("green").unwrap();
+
+    for kind in [
+        ColorBlindness::Protanopia,
+        ColorBlindness::Deuteranopia,
+        ColorBlindness::Tritanopia,
+    ] {
+        // neutral colors are seen the same way.
+        assert_eq!(white, white.simulate_color_blindness(kind));
+        assert_eq!(black, black.simulate_color_blindness(kind));
+    }
+
+    let simulated_red = red.simulate_color_blindness(ColorBlindness::Deuteranopia);
+    let simulated_green = green.simulate_color_blindness(ColorBlindness::Deuteranopia);
+    assert!(simulated_red.get_delta_e(&simulated_green) < red.get_delta_e(&green) / 2.0);
+
+    let transparent_red = Color::new_rgba(255, 0, 0, 128);
+    assert_eq!(
+        128,
+        transparent_red
+            .simulate_color_blindness(ColorBlindness::Protanopia)
+            .alpha
+    );
+}
+
+#[test]
+fn color_daltonize() {
+    let red = Color::new_rgb(200, 70, 50);
+    let green = Color::new_rgb(90, 140, 40);
+    let kind = ColorBlindness::Deuteranopia;
+
+    let original_delta_e = red
+        .simulate_color_blindness(kind)
+        .get_delta_e(&green.simulate_color_blindness(kind));
+    let daltonized_red = red.daltonize(kind, 1.0);
+    let daltonized_green = green.daltonize(kind, 1.0);
+    let daltonized_delta_e = daltonized_red
+        .simulate_color_blindness(kind)
+        .get_delta_e(&daltonized_green.simulate_color_blindness(kind));
+    assert!(daltonized_delta_e > original_delta_e);
+
+    assert_eq!(red, red.daltonize(kind, 0.0));
+    let white = Color::new_rgb(255, 255, 255);
+    assert_eq!(white, white.daltonize(kind, 1.0));
+
+    let transparent_red = Color::new_rgba(200, 70, 50, 128);
+    assert_eq!(128, transparent_red.daltonize(kind, 1.0).alpha);
+
+    // for tritanopia the lost information is shifted into the red and green channels, which are still visible.
+    let blue = Color::new_rgb(60, 90, 200);
+    let tritan_blue = blue.daltonize(ColorBlindness::Tritanopia, 1.0);
+    assert_ne!(blue.red, tritan_blue.red);
+    assert_ne!(blue.green, tritan_blue.green);
+    assert_eq!(blue.blue, tritan_blue.blue);
+    assert!(
+        blue.simulate_color_blindness(ColorBlindness::Tritanopia)
+            .get_delta_e(&tritan_blue.simulate_color_blindness(ColorBlindness::Tritanopia))
+            > 2.0
+    );
+}
+
+#[test]
+fn color_dominant_from_gradient() {
+    let red = Color::new_string("red").unwrap();
+    let blue = Color::new_string("blue").unwrap();
+
+    let representative = Color::dominant_from_gradient(&[red.clone(), blue.clone()]).unwrap();
+    // a purple-ish color, that is neither of the endpoints.
+    assert!(representative.red > 100);
+    assert!(representative.blue > 100);
+    assert!(representative.green < 50);
+    assert!(representative.get_delta_e(&red) > 10.0);
+    assert!(representative.get_delta_e(&blue) > 10.0);
+
+    // a gradient with more stops weights each segment equally.
+    let white = Color::new_rgb(255, 255, 255);
+    let black = Color::new_rgb(0, 0, 0);
+    assert_eq!(
+        Color::dominant_from_gradient(&[white.clone(), black.clone()]),
+        Color::dominant_from_gradient(&[
+            white.clone(),
+            white.perceptual_midpoint(&black),
+            black.clone()
+        ])
+    );
+
+    let transparent_red = Color::new_rgba(255, 0, 0, 0);
+    let opaque_red = Color::new_rgb(255, 0, 0);
+    let half_transparent_red =
+        Color::dominant_from_gradient(&[transparent_red, opaque_red]).unwrap();
+    assert_eq!(128, half_transparent_red.alpha);
+
+    assert_eq!(Some(white.clone()), Color::dominant_from_gradient(&[white]));
+    assert_eq!(None, Color::dominant_from_gradient(&[]));
+}
+
+#[test]
+fn color_gradient_through() {
+    let red = Color::new_string("red").unwrap();
+    let green = Color::new_string("lime").unwrap();
+    let blue = Color::new_string("blue").unwrap();
+    let keys = [red.clone(), green.clone(), blue.clone()];
+
+    let colors = Color::gradient_through(&keys, 9, MixSpace::Lab);
+    assert_eq!(9, colors.len());
+    assert_eq!(red.channels(), colors[0].channels());
+    assert_eq!(green.channels(), colors[4].channels());
+    assert_eq!(blue.channels(), colors[8].channels());
+    assert_eq!(red.mix(&green, 0.5, MixSpace::Lab), colors[2]);
+    assert_eq!(green.mix(&blue, 0.25, MixSpace::Lab), colors[5]);
+
+    let colors = Color::gradient_through(&keys, 7, MixSpace::Rgb);
+    assert_eq!(7, colors.len());
+    assert_eq!(red.channels(), colors[0].channels());
+    assert_eq!(green.channels(), colors[3].channels());
+    assert_eq!(blue.channels(), colors[6].channels());
+
+    // the samples don't need to hit the key colors in between.
+    let colors = Color::gradient_through(&keys, 4, MixSpace::Lch);
+    assert_eq!(4, colors.len());
+    assert_eq!(red.channels(), colors[0].channels());
+    assert_eq!(blue.channels(), colors[3].channels());
+
+    assert_eq!(
+        vec![red.channels()],
+        Color::gradient_through(&keys, 1, MixSpace::Lab)
+            .iter()
+            .map(|color| color.channels())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        3,
+        Color::gradient_through(&keys[..1], 3, MixSpace::Lab).len()
+    );
+    assert!(Color::gradient_through(&keys, 0, MixSpace::Lab).is_empty());
+    assert!(Color::gradient_through(&[], 5, MixSpace::Lab).is_empty());
+}
+
+#[test]
+fn color_lighten_until_contrast() {
+    let background = Color::new_rgb(40, 40, 40);
+    let blue = Color::new_rgb(50, 80, 160);
+    assert!(blue.get_contrast(background.clone()) < 4.5);
+
+    let readable_blue = blue.lighten_until_contrast(&background, 4.5).unwrap();
+    let contrast = readable_blue.get_contrast(background.clone());
+    assert!(contrast >= 4.5);
+    assert!(contrast < 4.7);
+    assert!(readable_blue.get_lcha().0 > blue.get_lcha().0);
+    assert!((readable_blue.get_lcha().2 - blue.get_lcha().2).abs() < 3.0);
+
+    // lightening can't give a higher contrast than white on this background.
+    let white = Color::new_rgb(255, 255, 255);
+    assert!(white.get_contrast(background.clone()) < 16.0);
+    assert_eq!(None, blue.lighten_until_contrast(&background, 16.0));
+
+    // lightening away from a light background never helps.
+    let light_background = Color::new_rgb(240, 240, 240);
+    assert_eq!(None, blue.lighten_until_contrast(&light_background, 21.0));
+
+    // colors, that already have the contrast, are returned unchanged.
+    assert_eq!(
+        Some(white.clone()),
+        white.lighten_until_contrast(&background, 4.5)
+    );
+}
+
+#[test]
+fn color_darken_until_contrast() {
+    let background = Color::new_rgb(240, 240, 240);
+    let orange = Color::new_rgb(255, 165, 0);
+    assert!(orange.get_contrast(background.clone()) < 4.5);
+
+    let readable_orange = orange.darken_until_contrast(&background, 4.5).unwrap();
+    let contrast = readable_orange.get_contrast(background.clone());
+    assert!(contrast >= 4.5);
+    assert!(contrast < 4.7);
+    assert!(readable_orange.get_lcha().0 < orange.get_lcha().0);
+
+    assert_eq!(None, orange.darken_until_contrast(&background, 21.0));
+
+    let transparent_orange = Color::new_rgba(255, 165, 0, 128);
+    assert_eq!(
+        128,
+        transparent_orange
+            .darken_until_contrast(&background, 4.5)
+            .unwrap()
+            .alpha
+    );
+}